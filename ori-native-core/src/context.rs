@@ -18,6 +18,10 @@ pub struct Context<P> {
     layout_tree:          taffy::TaffyTree<Box<dyn LayoutLeaf<P>>>,
     layout_controller:    Option<ViewId>,
     animation_controller: Option<ViewId>,
+    drag_payload:         Option<Box<dyn Any>>,
+    handled_key:          Option<u64>,
+    in_scroll:            bool,
+    focused:              Option<String>,
     resources:            Vec<Box<dyn Any>>,
 }
 
@@ -31,6 +35,10 @@ where
             layout_tree: taffy::TaffyTree::new(),
             layout_controller: None,
             animation_controller: None,
+            drag_payload: None,
+            handled_key: None,
+            in_scroll: false,
+            focused: None,
             resources: Vec::new(),
         }
     }
@@ -117,10 +125,26 @@ where
             .set_node_context(node, Some(Box::new(leaf)))
     }
 
+    pub fn mark_layout_dirty(&mut self, node: taffy::NodeId) -> taffy::TaffyResult<()> {
+        self.relayout();
+        self.layout_tree.mark_dirty(node)
+    }
+
     pub fn get_computed_layout(&self, node: taffy::NodeId) -> taffy::TaffyResult<&taffy::Layout> {
         self.layout_tree.layout(node)
     }
 
+    pub fn get_layout_children(
+        &self,
+        node: taffy::NodeId,
+    ) -> taffy::TaffyResult<Vec<taffy::NodeId>> {
+        self.layout_tree.children(node)
+    }
+
+    pub fn get_layout_parent(&self, node: taffy::NodeId) -> Option<taffy::NodeId> {
+        self.layout_tree.parent(node)
+    }
+
     pub fn compute_layout(
         &mut self,
         node: taffy::NodeId,
@@ -171,6 +195,41 @@ where
         }
     }
 
+    pub fn set_drag_payload(&mut self, payload: Option<Box<dyn Any>>) {
+        self.drag_payload = payload;
+    }
+
+    pub fn drag_payload(&self) -> Option<&dyn Any> {
+        self.drag_payload.as_deref()
+    }
+
+    pub fn set_handled_key(&mut self, serial: u64) {
+        self.handled_key = Some(serial);
+    }
+
+    pub fn handled_key(&self) -> Option<u64> {
+        self.handled_key
+    }
+
+    pub fn set_focused(&mut self, id: Option<String>) {
+        self.focused = id;
+    }
+
+    pub fn focused(&self) -> Option<&str> {
+        self.focused.as_deref()
+    }
+
+    pub fn is_in_scroll(&self) -> bool {
+        self.in_scroll
+    }
+
+    pub fn with_scroll<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let previous = std::mem::replace(&mut self.in_scroll, true);
+        let output = f(self);
+        self.in_scroll = previous;
+        output
+    }
+
     pub fn with_layout_controller<T>(
         &mut self,
         view_id: ViewId,
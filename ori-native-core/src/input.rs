@@ -0,0 +1,311 @@
+use std::{fmt, str::FromStr};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl:  bool,
+    pub alt:   bool,
+    pub meta:  bool,
+}
+
+impl Modifiers {
+    pub const NONE: Self = Self {
+        shift: false,
+        ctrl:  false,
+        alt:   false,
+        meta:  false,
+    };
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::NONE
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PointerButton {
+    Primary,
+    Secondary,
+    Middle,
+    Other(u32),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    Character(String),
+    Enter,
+    Tab,
+    Space,
+    Backspace,
+    Delete,
+    Escape,
+    Insert,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    ArrowUp,
+    ArrowDown,
+    ArrowLeft,
+    ArrowRight,
+    F(u8),
+    Shift,
+    Control,
+    Alt,
+    Meta,
+    CapsLock,
+    Unidentified(u32),
+}
+
+impl Key {
+    pub fn is_modifier(&self) -> bool {
+        matches!(
+            self,
+            Key::Shift | Key::Control | Key::Alt | Key::Meta | Key::CapsLock
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeyPhase {
+    Pressed,
+    Released,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    pub key:       Key,
+    pub phase:     KeyPhase,
+    pub modifiers: Modifiers,
+    pub repeat:    bool,
+}
+
+impl KeyEvent {
+    pub fn is_pressed(&self) -> bool {
+        self.phase == KeyPhase::Pressed
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    pub key:       Key,
+    pub modifiers: Modifiers,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseShortcutError {
+    pub shortcut: String,
+}
+
+impl fmt::Display for ParseShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid shortcut `{}`",
+            self.shortcut
+        )
+    }
+}
+
+impl std::error::Error for ParseShortcutError {}
+
+impl FromStr for Shortcut {
+    type Err = ParseShortcutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseShortcutError {
+            shortcut: s.to_owned(),
+        };
+
+        // the key is the last part, so that `Ctrl++` binds the plus key
+        let (modifiers, key) = match s.strip_suffix('+') {
+            Some("") => (None, "+"),
+            Some(rest) if rest.ends_with('+') => (Some(&rest[..rest.len() - 1]), "+"),
+            _ => match s.rsplit_once('+') {
+                Some((modifiers, key)) => (Some(modifiers), key),
+                None => (None, s),
+            },
+        };
+
+        let mut shortcut = Shortcut {
+            key:       parse_key(key.trim()).ok_or_else(err)?,
+            modifiers: Modifiers::NONE,
+        };
+
+        // empty modifiers, like in `Ctrl++A`, are rejected by the match below
+        for modifier in modifiers
+            .into_iter()
+            .flat_map(|m| m.split('+'))
+            .map(str::trim)
+        {
+            match modifier.to_lowercase().as_str() {
+                "shift" => shortcut.modifiers.shift = true,
+                "ctrl" | "control" | "primary" => shortcut.modifiers.ctrl = true,
+                "alt" | "option" => shortcut.modifiers.alt = true,
+                "meta" | "super" | "cmd" | "command" => shortcut.modifiers.meta = true,
+                _ => return Err(err()),
+            }
+        }
+
+        Ok(shortcut)
+    }
+}
+
+fn parse_key(key: &str) -> Option<Key> {
+    let mut chars = key.chars();
+
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return match c {
+            ' ' => Some(Key::Space),
+            c => Some(Key::Character(
+                c.to_lowercase().collect(),
+            )),
+        };
+    }
+
+    let key = match key.to_lowercase().as_str() {
+        "enter" | "return" => Key::Enter,
+        "tab" => Key::Tab,
+        "space" => Key::Space,
+        "backspace" => Key::Backspace,
+        "delete" | "del" => Key::Delete,
+        "escape" | "esc" => Key::Escape,
+        "insert" => Key::Insert,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        "up" | "arrowup" => Key::ArrowUp,
+        "down" | "arrowdown" => Key::ArrowDown,
+        "left" | "arrowleft" => Key::ArrowLeft,
+        "right" | "arrowright" => Key::ArrowRight,
+        key => {
+            let n = key.strip_prefix('f')?.parse().ok()?;
+            Key::F(n)
+        }
+    };
+
+    Some(key)
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CursorIcon {
+    #[default]
+    Default,
+    Hidden,
+    Pointer,
+    Text,
+    Crosshair,
+    Move,
+    Grab,
+    Grabbing,
+    NotAllowed,
+    Wait,
+    Progress,
+    Help,
+    ResizeHorizontal,
+    ResizeVertical,
+    ResizeNwse,
+    ResizeNesw,
+    ResizeColumn,
+    ResizeRow,
+    ZoomIn,
+    ZoomOut,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shortcut(key: Key, modifiers: Modifiers) -> Shortcut {
+        Shortcut { key, modifiers }
+    }
+
+    fn ctrl() -> Modifiers {
+        Modifiers {
+            ctrl: true,
+            ..Modifiers::NONE
+        }
+    }
+
+    #[test]
+    fn parse_shortcut() {
+        let parsed = Shortcut::from_str("Ctrl+Shift+S").unwrap();
+        let modifiers = Modifiers {
+            shift: true,
+            ..ctrl()
+        };
+
+        assert_eq!(
+            parsed,
+            shortcut(Key::Character("s".into()), modifiers)
+        );
+    }
+
+    #[test]
+    fn parse_shortcut_plus() {
+        let plus = Key::Character("+".into());
+
+        assert_eq!(
+            Shortcut::from_str("+"),
+            Ok(shortcut(plus.clone(), Modifiers::NONE))
+        );
+        assert_eq!(
+            Shortcut::from_str("Ctrl++"),
+            Ok(shortcut(plus, ctrl()))
+        );
+    }
+
+    #[test]
+    fn parse_shortcut_case() {
+        let expected = shortcut(Key::PageUp, ctrl());
+
+        assert_eq!(
+            Shortcut::from_str("ctrl+pageup"),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            Shortcut::from_str("CONTROL + PageUp"),
+            Ok(expected)
+        );
+
+        assert_eq!(
+            Shortcut::from_str("Ctrl+A"),
+            Ok(shortcut(
+                Key::Character("a".into()),
+                ctrl()
+            )),
+        );
+    }
+
+    #[test]
+    fn parse_shortcut_named_keys() {
+        assert_eq!(
+            Shortcut::from_str("F12").map(|s| s.key),
+            Ok(Key::F(12))
+        );
+        assert_eq!(
+            Shortcut::from_str("Esc").map(|s| s.key),
+            Ok(Key::Escape)
+        );
+        assert_eq!(
+            Shortcut::from_str("Space").map(|s| s.key),
+            Ok(Key::Space)
+        );
+    }
+
+    #[test]
+    fn parse_shortcut_unknown() {
+        assert!(Shortcut::from_str("Ctrl+Banana").is_err());
+        assert!(Shortcut::from_str("Hyper+A").is_err());
+        assert!(Shortcut::from_str("F999").is_err());
+    }
+
+    #[test]
+    fn parse_shortcut_empty_segments() {
+        assert!(Shortcut::from_str("").is_err());
+        assert!(Shortcut::from_str("Ctrl+").is_err());
+        assert!(Shortcut::from_str("+A").is_err());
+        assert!(Shortcut::from_str("++").is_err());
+        assert!(Shortcut::from_str("Ctrl++A").is_err());
+    }
+}
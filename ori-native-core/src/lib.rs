@@ -1,7 +1,9 @@
 mod color;
 mod context;
 mod element;
+mod input;
 mod lifecycle;
+mod painter;
 mod platform;
 mod style;
 mod text;
@@ -12,7 +14,14 @@ pub mod views;
 pub use color::Color;
 pub use context::{BoxedEffect, Context, LayoutLeaf};
 pub use element::{BoxedWidget, NativeWidget, Pod, PodMut, WidgetView};
+pub use input::{
+    CursorIcon, Key, KeyEvent, KeyPhase, Modifiers, ParseShortcutError, PointerButton, Shortcut,
+};
 pub use lifecycle::Lifecycle;
+pub use painter::{
+    Affine, DrawCommand, FillRule, GradientStop, LineCap, LineJoin, Paint, Painter, Path,
+    PathElement, RgbaImage, Stroke,
+};
 pub use platform::Platform;
 pub use style::{
     Align, AutoLength, BorderLayout, ContainerLayout, Direction, FlexLayout, Fraction, Justify,
@@ -20,4 +29,4 @@ pub use style::{
 };
 pub use text::{Font, Stretch, TextSpan, Weight};
 
-pub use taffy::{NodeId, Size};
+pub use taffy::{NodeId, Point, Size};
@@ -0,0 +1,19 @@
+use std::rc::Rc;
+
+use crate::{DrawCommand, NativeWidget, Platform};
+
+pub trait HasCanvas: Platform {
+    type Canvas: NativeCanvas<Self>;
+}
+
+pub trait NativeCanvas<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+
+    fn draw(&mut self, platform: &mut P, commands: Rc<[DrawCommand]>);
+}
@@ -0,0 +1,90 @@
+use crate::{Platform, RgbaImage};
+
+pub trait HasClipboard: Platform {
+    fn write_clipboard(&mut self, content: ClipboardContent);
+
+    // reads are asynchronous on most platforms, the callback is called with `None` when the
+    // clipboard doesn't hold the requested format
+    fn read_clipboard(
+        &mut self,
+        format: ClipboardFormat,
+        on_read: impl FnOnce(Option<ClipboardContent>) + 'static,
+    );
+}
+
+// platforms without a system clipboard, like headless ones used in tests, keep it in memory
+pub trait HasMemoryClipboard: Platform {
+    fn memory_clipboard(&mut self) -> &mut MemoryClipboard;
+}
+
+impl<P> HasClipboard for P
+where
+    P: HasMemoryClipboard,
+{
+    fn write_clipboard(&mut self, content: ClipboardContent) {
+        self.memory_clipboard().write(content);
+    }
+
+    fn read_clipboard(
+        &mut self,
+        format: ClipboardFormat,
+        on_read: impl FnOnce(Option<ClipboardContent>) + 'static,
+    ) {
+        on_read(self.memory_clipboard().read(&format));
+    }
+}
+
+// like a system clipboard, it holds a single content at a time
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemoryClipboard {
+    content: Option<ClipboardContent>,
+}
+
+impl MemoryClipboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn write(&mut self, content: ClipboardContent) {
+        self.content = Some(content);
+    }
+
+    pub fn read(&self, format: &ClipboardFormat) -> Option<ClipboardContent> {
+        (self.content.as_ref())
+            .filter(|content| content.format() == *format)
+            .cloned()
+    }
+
+    pub fn clear(&mut self) {
+        self.content = None;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClipboardFormat {
+    Text,
+    Image,
+    Custom(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClipboardContent {
+    Text(String),
+    Image(RgbaImage),
+    Custom {
+        mime_type: String,
+        data:      Vec<u8>,
+    },
+}
+
+impl ClipboardContent {
+    pub fn format(&self) -> ClipboardFormat {
+        match self {
+            ClipboardContent::Text(_) => ClipboardFormat::Text,
+            ClipboardContent::Image(_) => ClipboardFormat::Image,
+            ClipboardContent::Custom { mime_type, .. } => {
+                ClipboardFormat::Custom(mime_type.clone())
+            }
+        }
+    }
+}
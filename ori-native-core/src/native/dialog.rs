@@ -0,0 +1,43 @@
+use crate::{NativeWidget, Platform};
+
+// dialogs are placed in the tree of the window they belong to, the widget is only a placeholder
+// that ties them to it
+
+pub trait HasDialog: Platform {
+    type Dialog: NativeDialog<Self>;
+}
+
+pub trait NativeDialog<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, content: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_title(&mut self, title: &str);
+    fn set_content_size(&mut self, width: f32, height: f32);
+    fn set_open(&mut self, open: bool);
+    fn set_on_close_requested(&mut self, on_close_requested: impl Fn() + 'static);
+}
+
+pub trait HasAlert: Platform {
+    type Alert: NativeAlert<Self>;
+}
+
+pub trait NativeAlert<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    // replaces the alert being shown, if any, the callback is called with the index of the
+    // chosen button, or `None` when the alert is dismissed
+    fn show(
+        &mut self,
+        title: &str,
+        message: &str,
+        buttons: &[String],
+        on_response: impl Fn(Option<usize>) + 'static,
+    );
+}
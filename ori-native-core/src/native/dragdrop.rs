@@ -0,0 +1,73 @@
+use std::{any::TypeId, path::PathBuf};
+
+use crate::{NativeWidget, Platform, Point};
+
+pub trait HasDragSource: Platform {
+    type DragSource: NativeDragSource<Self>;
+}
+
+pub trait NativeDragSource<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_disabled(&mut self, disabled: bool);
+    fn set_text(&mut self, text: Option<String>);
+    fn set_payload_type(&mut self, payload: TypeId);
+    fn set_on_drag(&mut self, on_drag: impl Fn(bool) + 'static);
+}
+
+pub trait HasDropTarget: Platform {
+    type DropTarget: NativeDropTarget<Self>;
+}
+
+pub trait NativeDropTarget<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_disabled(&mut self, disabled: bool);
+    fn set_accepts(&mut self, accepts: DropAccepts);
+    fn set_on_drop(&mut self, on_drop: impl Fn(DropEvent) + 'static);
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DropAccepts {
+    pub app:   Option<TypeId>,
+    pub files: bool,
+    pub text:  bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DropKind {
+    App,
+    Files,
+    Text,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DroppedFile {
+    pub uri:  String,
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DropData {
+    App,
+    Files(Vec<DroppedFile>),
+    Text(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DropEvent {
+    Entered(DropKind, Point<f32>),
+    Moved(Point<f32>),
+    Left,
+    Dropped(DropData, Point<f32>),
+}
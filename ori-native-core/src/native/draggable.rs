@@ -0,0 +1,34 @@
+use crate::{Modifiers, NativeWidget, Platform, Point};
+
+pub trait HasDraggable: Platform {
+    type Draggable: NativeDraggable<Self>;
+}
+
+pub trait NativeDraggable<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_disabled(&mut self, disabled: bool);
+    fn set_on_drag(&mut self, on_drag: impl Fn(DragEvent) + 'static);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DragPhase {
+    Began,
+    Moved,
+    Ended,
+    Cancelled,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DragEvent {
+    pub phase:     DragPhase,
+    pub start:     Point<f32>,
+    pub offset:    Point<f32>,
+    pub velocity:  Point<f32>,
+    pub modifiers: Modifiers,
+}
@@ -0,0 +1,74 @@
+use std::path::PathBuf;
+
+use crate::{NativeWidget, Platform};
+
+pub trait HasFileDialog: Platform {
+    type FileDialog: NativeFileDialog<Self>;
+}
+
+pub trait NativeFileDialog<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    // replaces the dialog being shown, if any, the callback is called with `None` when the
+    // dialog is cancelled
+    fn show(
+        &mut self,
+        options: &FileDialogOptions,
+        on_result: impl Fn(Option<Vec<SelectedFile>>) + 'static,
+    );
+}
+
+// files without a local path, like remote locations, are only known by their uri
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SelectedFile {
+    pub uri:  String,
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FileDialogMode {
+    Open,
+    OpenMultiple,
+    Save,
+    Folder,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FileFilter {
+    pub name:       String,
+    pub patterns:   Vec<String>,
+    pub mime_types: Vec<String>,
+}
+
+impl FileFilter {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name:       name.into(),
+            patterns:   Vec::new(),
+            mime_types: Vec::new(),
+        }
+    }
+
+    pub fn pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.patterns.push(pattern.into());
+        self
+    }
+
+    pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_types.push(mime_type.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FileDialogOptions {
+    pub mode:      FileDialogMode,
+    pub title:     Option<String>,
+    pub filters:   Vec<FileFilter>,
+    pub directory: Option<PathBuf>,
+    pub name:      Option<String>,
+}
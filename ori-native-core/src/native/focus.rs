@@ -0,0 +1,24 @@
+use crate::{NativeWidget, Platform};
+
+pub trait HasFocusTarget: Platform {
+    type FocusTarget: NativeFocusTarget<Self>;
+}
+
+pub trait NativeFocusTarget<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_tab_index(&mut self, tab_index: i32);
+    fn set_trap(&mut self, trap: bool);
+
+    // called with true when focus enters the target or any of its contents, and false when
+    // it leaves
+    fn set_on_focus(&mut self, on_focus: impl Fn(bool) + 'static);
+
+    // moves focus to the target, or the first focusable widget in its contents
+    fn focus(&mut self);
+}
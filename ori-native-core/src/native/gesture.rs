@@ -0,0 +1,41 @@
+use crate::{NativeWidget, Platform, Point};
+
+pub trait HasGesture: Platform {
+    type Gesture: NativeGesture<Self>;
+}
+
+pub trait NativeGesture<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_disabled(&mut self, disabled: bool);
+    fn set_on_zoom(&mut self, on_zoom: impl Fn(ZoomEvent) + 'static);
+    fn set_on_rotate(&mut self, on_rotate: impl Fn(RotateEvent) + 'static);
+    fn set_on_swipe(&mut self, on_swipe: impl Fn(Point<f32>) + 'static);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GesturePhase {
+    Began,
+    Changed,
+    Ended,
+    Cancelled,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZoomEvent {
+    pub phase: GesturePhase,
+    pub scale: f32,
+    pub focal: Point<f32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RotateEvent {
+    pub phase: GesturePhase,
+    pub angle: f32,
+    pub focal: Point<f32>,
+}
@@ -19,6 +19,9 @@ where
 
     fn swap_children(&mut self, index_a: usize, index_b: usize);
 
+    // raised widgets are drawn over their siblings, without changing their order otherwise
+    fn set_raised(&mut self, raised: bool);
+
     fn set_size(&mut self, width: f32, height: f32);
 
     fn set_child_layout(&mut self, index: usize, x: f32, y: f32, width: f32, height: f32);
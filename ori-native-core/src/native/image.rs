@@ -1,4 +1,4 @@
-use std::{borrow::Cow, error::Error};
+use std::{borrow::Cow, error::Error, sync::Arc};
 
 use crate::{Color, LayoutLeaf, NativeWidget, Platform};
 
@@ -21,5 +21,38 @@ where
         data: Cow<'static, [u8]>,
     ) -> Result<impl LayoutLeaf<P>, Self::Error>;
 
+    fn load_rgba(
+        &mut self,
+        plaform: &mut P,
+        width: u32,
+        height: u32,
+        pixels: Arc<[u8]>,
+    ) -> Result<impl LayoutLeaf<P>, Self::Error>;
+
+    fn load_icon(
+        &mut self,
+        plaform: &mut P,
+        name: &str,
+        size: u32,
+        scale: Option<u32>,
+    ) -> Result<impl LayoutLeaf<P>, Self::Error>;
+
     fn set_tint(&mut self, tint: Option<Color>);
+
+    fn set_svg_options(&mut self, options: &SvgOptions);
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SvgOptions {
+    pub view_box:      Option<ViewBox>,
+    pub stylesheet:    Option<Cow<'static, str>>,
+    pub current_color: Option<Color>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewBox {
+    pub x:      f32,
+    pub y:      f32,
+    pub width:  f32,
+    pub height: f32,
 }
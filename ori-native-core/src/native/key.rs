@@ -0,0 +1,47 @@
+use crate::{KeyEvent, NativeWidget, Platform, Shortcut};
+
+pub trait HasKeyHandler: Platform {
+    type KeyHandler: NativeKeyHandler<Self>;
+}
+
+pub trait NativeKeyHandler<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_disabled(&mut self, disabled: bool);
+    fn set_focusable(&mut self, focusable: bool);
+
+    // the serial identifies the platform event, it's shared by every handler the event
+    // propagates through, the platform's own handling of the event isn't affected by it
+    fn set_on_key(&mut self, on_key: impl Fn(u64, KeyEvent) + 'static);
+}
+
+pub trait HasShortcuts: Platform {
+    type Shortcuts: NativeShortcuts<Self>;
+}
+
+pub trait NativeShortcuts<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_disabled(&mut self, disabled: bool);
+    fn set_scope(&mut self, scope: ShortcutScope);
+
+    // the callback is called with the index of the triggered shortcut
+    fn set_shortcuts(&mut self, shortcuts: &[Shortcut], on_shortcut: impl Fn(usize) + 'static);
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ShortcutScope {
+    #[default]
+    Subtree,
+    Window,
+}
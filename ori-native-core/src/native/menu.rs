@@ -0,0 +1,34 @@
+use crate::{NativeWidget, Platform};
+
+pub trait HasContextMenu: Platform {
+    type ContextMenu: NativeContextMenu<Self>;
+}
+
+pub trait NativeContextMenu<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_disabled(&mut self, disabled: bool);
+
+    // items are numbered depth first, the callback is called with the number of the selected
+    // item
+    fn set_menu(&mut self, entries: &[MenuEntry], on_select: impl Fn(usize) + 'static);
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MenuEntry {
+    Item {
+        label:   String,
+        checked: Option<bool>,
+        enabled: bool,
+    },
+    Separator,
+    Submenu {
+        label:   String,
+        entries: Vec<MenuEntry>,
+    },
+}
@@ -1,15 +1,48 @@
+mod canvas;
+mod clipboard;
+mod dialog;
+mod dragdrop;
+mod draggable;
+mod file_dialog;
+mod focus;
+mod gesture;
 mod group;
 mod image;
+mod key;
+mod menu;
+mod pointer;
+mod popover;
 mod pressable;
 mod scroll;
 mod text;
 mod textinput;
+mod tooltip;
 mod window;
 
+pub use canvas::{HasCanvas, NativeCanvas};
+pub use clipboard::{
+    ClipboardContent, ClipboardFormat, HasClipboard, HasMemoryClipboard, MemoryClipboard,
+};
+pub use dialog::{HasAlert, HasDialog, NativeAlert, NativeDialog};
+pub use dragdrop::{
+    DropAccepts, DropData, DropEvent, DropKind, DroppedFile, HasDragSource, HasDropTarget,
+    NativeDragSource, NativeDropTarget,
+};
+pub use draggable::{DragEvent, DragPhase, HasDraggable, NativeDraggable};
+pub use file_dialog::{
+    FileDialogMode, FileDialogOptions, FileFilter, HasFileDialog, NativeFileDialog, SelectedFile,
+};
+pub use focus::{HasFocusTarget, NativeFocusTarget};
+pub use gesture::{GesturePhase, HasGesture, NativeGesture, RotateEvent, ZoomEvent};
 pub use group::{Group, HasGroup, NativeGroup};
-pub use image::{HasImage, NativeImage};
-pub use pressable::{HasPressable, NativePressable, Press};
-pub use scroll::{HasScroll, NativeScroll};
+pub use image::{HasImage, NativeImage, SvgOptions, ViewBox};
+pub use key::{HasKeyHandler, HasShortcuts, NativeKeyHandler, NativeShortcuts, ShortcutScope};
+pub use menu::{HasContextMenu, MenuEntry, NativeContextMenu};
+pub use pointer::{HasPointer, NativePointer, PointerEvent};
+pub use popover::{HasPopover, NativePopover, PopoverPosition};
+pub use pressable::{HasPressable, NativePressable, Press, PressEvent};
+pub use scroll::{HasScroll, NativeScroll, ScrollOffset, ScrollbarPolicy};
 pub use text::{HasText, NativeText};
 pub use textinput::{HasTextInput, NativeTextInput};
+pub use tooltip::{HasTooltip, NativeTooltip};
 pub use window::{HasWindow, NativeWindow};
@@ -0,0 +1,25 @@
+use crate::{CursorIcon, NativeWidget, Platform, Point};
+
+pub trait HasPointer: Platform {
+    type Pointer: NativePointer<Self>;
+}
+
+pub trait NativePointer<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, contents: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_disabled(&mut self, disabled: bool);
+    fn set_cursor(&mut self, cursor: Option<CursorIcon>);
+    fn set_on_pointer(&mut self, on_pointer: impl Fn(PointerEvent) + 'static);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PointerEvent {
+    Entered(Point<f32>),
+    Moved(Point<f32>),
+    Left,
+}
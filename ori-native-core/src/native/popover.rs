@@ -0,0 +1,30 @@
+use crate::{NativeWidget, Platform};
+
+pub trait HasPopover: Platform {
+    type Popover: NativePopover<Self>;
+}
+
+pub trait NativePopover<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    fn build(platform: &mut P, anchor: &P::Widget, content: &P::Widget) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_content_size(&mut self, width: f32, height: f32);
+    fn set_position(&mut self, position: PopoverPosition);
+    fn set_open(&mut self, open: bool);
+
+    // called when the popover is dismissed by the user, e.g. by clicking outside of it
+    fn set_on_close(&mut self, on_close: impl Fn() + 'static);
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PopoverPosition {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
@@ -1,4 +1,4 @@
-use crate::{NativeWidget, Platform};
+use crate::{Modifiers, NativeWidget, Platform, Point, PointerButton};
 
 pub trait HasPressable: Platform {
     type Pressable: NativePressable<Self>;
@@ -12,9 +12,14 @@ where
     fn teardown(self, plaform: &mut P);
 
     fn set_size(&mut self, width: f32, height: f32);
-    fn set_on_press(&mut self, on_press: impl Fn(Press) + 'static);
+    fn set_on_press(&mut self, on_press: impl Fn(PressEvent) + 'static);
     fn set_on_hover(&mut self, on_hover: impl Fn(bool) + 'static);
     fn set_on_focus(&mut self, on_focus: impl Fn(bool) + 'static);
+
+    fn set_focusable(&mut self, focusable: bool);
+    fn set_tab_index(&mut self, tab_index: i32);
+    fn set_focus_ring(&mut self, focus_ring: bool);
+    fn set_disabled(&mut self, disabled: bool);
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -22,4 +27,14 @@ pub enum Press {
     Pressed,
     Released,
     Cancelled,
+    LongPressed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PressEvent {
+    pub press:     Press,
+    pub button:    PointerButton,
+    pub clicks:    u32,
+    pub position:  Point<f32>,
+    pub modifiers: Modifiers,
 }
@@ -1,4 +1,4 @@
-use crate::{Direction, NativeWidget, Platform};
+use crate::{NativeWidget, Platform, Size};
 
 pub trait HasScroll: Platform {
     type Scroll: NativeScroll<Self>;
@@ -13,5 +13,34 @@ where
 
     fn set_size(&mut self, width: f32, height: f32);
 
-    fn set_direction(&mut self, direction: Direction);
+    fn set_scrollbars(
+        &mut self,
+        horizontal: Option<ScrollbarPolicy>,
+        vertical: Option<ScrollbarPolicy>,
+    );
+
+    fn set_kinetic(&mut self, kinetic: bool);
+    fn set_stick_to_end(&mut self, stick_to_end: bool);
+    fn set_on_scroll(&mut self, on_scroll: impl Fn(ScrollOffset) + 'static);
+    fn set_on_overscroll(&mut self, on_overscroll: impl Fn() + 'static);
+
+    fn set_refreshing(&mut self, refreshing: bool, indicator: Option<&P::Widget>);
+
+    fn scroll_to(&mut self, x: Option<f32>, y: Option<f32>, animated: bool);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollOffset {
+    pub x:        f32,
+    pub y:        f32,
+    pub content:  Size<f32>,
+    pub viewport: Size<f32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScrollbarPolicy {
+    Always,
+    Automatic,
+    Never,
+    Overlay,
 }
@@ -16,6 +16,7 @@ where
 
     fn set_newline(&mut self, platform: &mut P, newline: Newline);
     fn set_accept_tab(&mut self, platform: &mut P, accept_tab: bool);
+    fn set_disabled(&mut self, platform: &mut P, disabled: bool);
 
     fn set_font(&mut self, platform: &mut P, font: Font);
     fn set_text(&mut self, platform: &mut P, text: String);
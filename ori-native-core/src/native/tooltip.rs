@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use crate::{NativeWidget, Platform};
+
+pub trait HasTooltip: Platform {
+    type Tooltip: NativeTooltip<Self>;
+}
+
+pub trait NativeTooltip<P>: NativeWidget<P>
+where
+    P: Platform,
+{
+    // without content the tooltip shows its text
+    fn build(platform: &mut P, contents: &P::Widget, content: Option<&P::Widget>) -> Self;
+    fn teardown(self, platform: &mut P);
+
+    fn set_size(&mut self, width: f32, height: f32);
+    fn set_content_size(&mut self, width: f32, height: f32);
+    fn set_text(&mut self, text: Option<String>);
+    fn set_delay(&mut self, delay: Duration);
+}
@@ -0,0 +1,536 @@
+use std::sync::Arc;
+
+use crate::{Color, Font};
+
+const KAPPA: f32 = 0.552_284_8;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Painter {
+    commands: Vec<DrawCommand>,
+}
+
+impl Painter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+
+    pub fn into_commands(self) -> Vec<DrawCommand> {
+        self.commands
+    }
+
+    pub fn fill(&mut self, path: &Path, paint: impl Into<Paint>) {
+        self.fill_with_rule(path, paint, FillRule::NonZero);
+    }
+
+    pub fn fill_with_rule(&mut self, path: &Path, paint: impl Into<Paint>, rule: FillRule) {
+        self.commands.push(DrawCommand::Fill {
+            path: path.clone(),
+            paint: paint.into(),
+            rule,
+        });
+    }
+
+    pub fn stroke(&mut self, path: &Path, paint: impl Into<Paint>, stroke: impl Into<Stroke>) {
+        self.commands.push(DrawCommand::Stroke {
+            path:   path.clone(),
+            paint:  paint.into(),
+            stroke: stroke.into(),
+        });
+    }
+
+    pub fn text(&mut self, x: f32, y: f32, text: impl Into<String>, font: &Font) {
+        self.commands.push(DrawCommand::Text {
+            x,
+            y,
+            text: text.into(),
+            font: font.clone(),
+        });
+    }
+
+    pub fn image(&mut self, x: f32, y: f32, width: f32, height: f32, image: &RgbaImage) {
+        self.commands.push(DrawCommand::Image {
+            x,
+            y,
+            width,
+            height,
+            image: image.clone(),
+        });
+    }
+
+    pub fn save(&mut self) {
+        self.commands.push(DrawCommand::Save);
+    }
+
+    pub fn restore(&mut self) {
+        self.commands.push(DrawCommand::Restore);
+    }
+
+    pub fn layer(&mut self, f: impl FnOnce(&mut Self)) {
+        self.save();
+        f(self);
+        self.restore();
+    }
+
+    pub fn transform(&mut self, affine: Affine) {
+        self.commands.push(DrawCommand::Transform(affine));
+    }
+
+    pub fn translate(&mut self, x: f32, y: f32) {
+        self.transform(Affine::translate(x, y));
+    }
+
+    pub fn scale(&mut self, x: f32, y: f32) {
+        self.transform(Affine::scale(x, y));
+    }
+
+    pub fn rotate(&mut self, angle: f32) {
+        self.transform(Affine::rotate(angle));
+    }
+
+    pub fn clip(&mut self, path: &Path) {
+        self.commands.push(DrawCommand::Clip(path.clone()));
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DrawCommand {
+    Fill {
+        path:  Path,
+        paint: Paint,
+        rule:  FillRule,
+    },
+    Stroke {
+        path:   Path,
+        paint:  Paint,
+        stroke: Stroke,
+    },
+    Text {
+        x:    f32,
+        y:    f32,
+        text: String,
+        font: Font,
+    },
+    Image {
+        x:      f32,
+        y:      f32,
+        width:  f32,
+        height: f32,
+        image:  RgbaImage,
+    },
+    Save,
+    Restore,
+    Transform(Affine),
+    Clip(Path),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path {
+    elements: Vec<PathElement>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathElement {
+    MoveTo(f32, f32),
+    LineTo(f32, f32),
+    QuadTo(f32, f32, f32, f32),
+    CubicTo(f32, f32, f32, f32, f32, f32),
+    Close,
+}
+
+impl Path {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn elements(&self) -> &[PathElement] {
+        &self.elements
+    }
+
+    pub fn move_to(&mut self, x: f32, y: f32) -> &mut Self {
+        self.elements.push(PathElement::MoveTo(x, y));
+        self
+    }
+
+    pub fn line_to(&mut self, x: f32, y: f32) -> &mut Self {
+        self.elements.push(PathElement::LineTo(x, y));
+        self
+    }
+
+    pub fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) -> &mut Self {
+        self.elements.push(PathElement::QuadTo(x1, y1, x, y));
+        self
+    }
+
+    pub fn cubic_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) -> &mut Self {
+        self.elements.push(PathElement::CubicTo(
+            x1, y1, x2, y2, x, y,
+        ));
+        self
+    }
+
+    pub fn close(&mut self) -> &mut Self {
+        self.elements.push(PathElement::Close);
+        self
+    }
+
+    pub fn rect(x: f32, y: f32, width: f32, height: f32) -> Self {
+        let mut path = Self::new();
+
+        path.move_to(x, y)
+            .line_to(x + width, y)
+            .line_to(x + width, y + height)
+            .line_to(x, y + height)
+            .close();
+
+        path
+    }
+
+    pub fn rounded_rect(x: f32, y: f32, width: f32, height: f32, radius: f32) -> Self {
+        let r = radius.min(width / 2.0).min(height / 2.0).max(0.0);
+        let k = r * (1.0 - KAPPA);
+
+        let mut path = Self::new();
+
+        path.move_to(x + r, y)
+            .line_to(x + width - r, y)
+            .cubic_to(
+                x + width - k,
+                y,
+                x + width,
+                y + k,
+                x + width,
+                y + r,
+            )
+            .line_to(x + width, y + height - r)
+            .cubic_to(
+                x + width,
+                y + height - k,
+                x + width - k,
+                y + height,
+                x + width - r,
+                y + height,
+            )
+            .line_to(x + r, y + height)
+            .cubic_to(
+                x + k,
+                y + height,
+                x,
+                y + height - k,
+                x,
+                y + height - r,
+            )
+            .line_to(x, y + r)
+            .cubic_to(x, y + k, x + k, y, x + r, y)
+            .close();
+
+        path
+    }
+
+    pub fn ellipse(cx: f32, cy: f32, rx: f32, ry: f32) -> Self {
+        let kx = rx * KAPPA;
+        let ky = ry * KAPPA;
+
+        let mut path = Self::new();
+
+        path.move_to(cx + rx, cy)
+            .cubic_to(
+                cx + rx,
+                cy + ky,
+                cx + kx,
+                cy + ry,
+                cx,
+                cy + ry,
+            )
+            .cubic_to(
+                cx - kx,
+                cy + ry,
+                cx - rx,
+                cy + ky,
+                cx - rx,
+                cy,
+            )
+            .cubic_to(
+                cx - rx,
+                cy - ky,
+                cx - kx,
+                cy - ry,
+                cx,
+                cy - ry,
+            )
+            .cubic_to(
+                cx + kx,
+                cy - ry,
+                cx + rx,
+                cy - ky,
+                cx + rx,
+                cy,
+            )
+            .close();
+
+        path
+    }
+
+    pub fn circle(cx: f32, cy: f32, radius: f32) -> Self {
+        Self::ellipse(cx, cy, radius, radius)
+    }
+
+    pub fn line(x0: f32, y0: f32, x1: f32, y1: f32) -> Self {
+        let mut path = Self::new();
+        path.move_to(x0, y0).line_to(x1, y1);
+        path
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FillRule {
+    NonZero,
+    EvenOdd,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Paint {
+    Color(Color),
+    LinearGradient {
+        start: (f32, f32),
+        end:   (f32, f32),
+        stops: Vec<GradientStop>,
+    },
+    RadialGradient {
+        center: (f32, f32),
+        radius: f32,
+        stops:  Vec<GradientStop>,
+    },
+}
+
+impl Paint {
+    pub fn linear_gradient(
+        start: (f32, f32),
+        end: (f32, f32),
+        stops: impl IntoIterator<Item = (f32, Color)>,
+    ) -> Self {
+        Paint::LinearGradient {
+            start,
+            end,
+            stops: stops.into_iter().map(GradientStop::from).collect(),
+        }
+    }
+
+    pub fn radial_gradient(
+        center: (f32, f32),
+        radius: f32,
+        stops: impl IntoIterator<Item = (f32, Color)>,
+    ) -> Self {
+        Paint::RadialGradient {
+            center,
+            radius,
+            stops: stops.into_iter().map(GradientStop::from).collect(),
+        }
+    }
+}
+
+impl From<Color> for Paint {
+    fn from(color: Color) -> Self {
+        Paint::Color(color)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GradientStop {
+    pub offset: f32,
+    pub color:  Color,
+}
+
+impl From<(f32, Color)> for GradientStop {
+    fn from((offset, color): (f32, Color)) -> Self {
+        Self { offset, color }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stroke {
+    pub width: f32,
+    pub cap:   LineCap,
+    pub join:  LineJoin,
+    pub dash:  Vec<f32>,
+}
+
+impl Default for Stroke {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            cap:   LineCap::Butt,
+            join:  LineJoin::Miter,
+            dash:  Vec::new(),
+        }
+    }
+}
+
+impl From<f32> for Stroke {
+    fn from(width: f32) -> Self {
+        Self {
+            width,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LineJoin {
+    Miter,
+    Round,
+    Bevel,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine {
+    pub xx: f32,
+    pub yx: f32,
+    pub xy: f32,
+    pub yy: f32,
+    pub x0: f32,
+    pub y0: f32,
+}
+
+impl Affine {
+    pub const IDENTITY: Self = Self {
+        xx: 1.0,
+        yx: 0.0,
+        xy: 0.0,
+        yy: 1.0,
+        x0: 0.0,
+        y0: 0.0,
+    };
+
+    pub fn translate(x: f32, y: f32) -> Self {
+        Self {
+            x0: x,
+            y0: y,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn scale(x: f32, y: f32) -> Self {
+        Self {
+            xx: x,
+            yy: y,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn rotate(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+
+        Self {
+            xx: cos,
+            yx: sin,
+            xy: -sin,
+            yy: cos,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn rotate_degrees(degrees: f32) -> Self {
+        Self::rotate(degrees.to_radians())
+    }
+
+    pub fn then(self, other: Self) -> Self {
+        Self {
+            xx: other.xx * self.xx + other.xy * self.yx,
+            yx: other.yx * self.xx + other.yy * self.yx,
+            xy: other.xx * self.xy + other.xy * self.yy,
+            yy: other.yx * self.xy + other.yy * self.yy,
+            x0: other.xx * self.x0 + other.xy * self.y0 + other.x0,
+            y0: other.yx * self.x0 + other.yy * self.y0 + other.y0,
+        }
+    }
+}
+
+impl Default for Affine {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RgbaImage {
+    pub width:  u32,
+    pub height: u32,
+    pub pixels: Arc<[u8]>,
+}
+
+impl RgbaImage {
+    pub fn new(width: u32, height: u32, pixels: impl Into<Arc<[u8]>>) -> Self {
+        Self {
+            width,
+            height,
+            pixels: pixels.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(affine: Affine, x: f32, y: f32) -> (f32, f32) {
+        (
+            affine.xx * x + affine.xy * y + affine.x0,
+            affine.yx * x + affine.yy * y + affine.y0,
+        )
+    }
+
+    fn assert_near(a: (f32, f32), b: (f32, f32)) {
+        assert!(
+            (a.0 - b.0).abs() < 1e-5 && (a.1 - b.1).abs() < 1e-5,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn then_identity() {
+        let affine = Affine::translate(3.0, 4.0).then(Affine::scale(2.0, 5.0));
+
+        assert_eq!(affine.then(Affine::IDENTITY), affine);
+        assert_eq!(Affine::IDENTITY.then(affine), affine);
+    }
+
+    #[test]
+    fn then_applies_self_first() {
+        // translate, then scale the translated point
+        let affine = Affine::translate(1.0, 2.0).then(Affine::scale(2.0, 3.0));
+        assert_near(apply(affine, 1.0, 1.0), (4.0, 9.0));
+
+        // scale, then translate the scaled point
+        let affine = Affine::scale(2.0, 3.0).then(Affine::translate(1.0, 2.0));
+        assert_near(apply(affine, 1.0, 1.0), (3.0, 5.0));
+    }
+
+    #[test]
+    fn then_matches_applying_in_order() {
+        let a = Affine::rotate_degrees(30.0).then(Affine::translate(5.0, -2.0));
+        let b = Affine::scale(0.5, 2.0).then(Affine::rotate_degrees(-75.0));
+
+        let (x, y) = apply(a, 3.0, 7.0);
+        assert_near(
+            apply(a.then(b), 3.0, 7.0),
+            apply(b, x, y),
+        );
+    }
+
+    #[test]
+    fn then_rotations_add() {
+        let affine = Affine::rotate_degrees(90.0).then(Affine::rotate_degrees(90.0));
+        assert_near(apply(affine, 1.0, 0.0), (-1.0, 0.0));
+    }
+}
@@ -0,0 +1,128 @@
+use std::rc::Rc;
+
+use ori::{Action, Message, Mut, View, ViewMarker};
+
+use crate::{
+    Context, DrawCommand, Layout, Lifecycle, Painter, Pod, Size,
+    native::{HasCanvas, NativeCanvas},
+};
+
+pub fn canvas<F>(draw: F) -> Canvas<F>
+where
+    F: Fn(&mut Painter, Size<f32>),
+{
+    Canvas::new(draw)
+}
+
+pub struct Canvas<F> {
+    style: taffy::Style,
+    draw:  F,
+}
+
+impl<F> Canvas<F> {
+    pub fn new(draw: F) -> Self {
+        Self {
+            style: Default::default(),
+            draw,
+        }
+    }
+}
+
+impl<F> Layout for Canvas<F> {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        &mut self.style
+    }
+}
+
+impl<F> ViewMarker for Canvas<F> {}
+impl<P, T, F> View<Context<P>, T> for Canvas<F>
+where
+    P: HasCanvas,
+    F: Fn(&mut Painter, Size<f32>),
+{
+    type Element = Pod<P::Canvas>;
+    type State = (F, Size<f32>, Rc<[DrawCommand]>);
+
+    fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let widget = P::Canvas::build(&mut cx.platform);
+        let node = cx.new_layout_node(self.style, &[]);
+
+        let pod = Pod { node, widget };
+
+        // nothing is drawn until the first layout, as the size isn't known yet
+        (
+            pod,
+            (self.draw, Size::ZERO, Rc::from([])),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (draw, size, commands): &mut Self::State,
+        cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        let _ = cx.set_layout_style(*element.node, self.style);
+
+        *draw = self.draw;
+        redraw(
+            element.widget,
+            cx,
+            draw,
+            *size,
+            commands,
+        );
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (draw, size, commands): &mut Self::State,
+        cx: &mut Context<P>,
+        _data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+            && layout.size != *size
+        {
+            *size = layout.size;
+
+            element.widget.set_size(size.width, size.height);
+            redraw(
+                element.widget,
+                cx,
+                draw,
+                *size,
+                commands,
+            );
+        }
+
+        Action::new()
+    }
+
+    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context<P>) {
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+fn redraw<P, F>(
+    widget: &mut P::Canvas,
+    cx: &mut Context<P>,
+    draw: &F,
+    size: Size<f32>,
+    commands: &mut Rc<[DrawCommand]>,
+) where
+    P: HasCanvas,
+    F: Fn(&mut Painter, Size<f32>),
+{
+    let mut painter = Painter::new();
+    draw(&mut painter, size);
+
+    // only hand the commands to the platform when they actually changed
+    if painter.commands() != commands.as_ref() {
+        *commands = Rc::from(painter.into_commands());
+        widget.draw(&mut cx.platform, commands.clone());
+    }
+}
@@ -0,0 +1,135 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context,
+    native::{ClipboardContent, ClipboardFormat, HasClipboard},
+};
+
+pub fn clipboard<T>() -> Clipboard<T> {
+    Clipboard::new()
+}
+
+pub fn write_clipboard(content: ClipboardContent) -> Message {
+    Message::new(ClipboardRequest::Write(content), None)
+}
+
+pub fn read_clipboard(format: ClipboardFormat) -> Message {
+    Message::new(ClipboardRequest::Read(format), None)
+}
+
+// delivered to `on_read` when a read requested with `read_clipboard` finishes
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClipboardRead {
+    pub format:  ClipboardFormat,
+    pub content: Option<ClipboardContent>,
+}
+
+#[derive(Clone, Debug)]
+enum ClipboardRequest {
+    Write(ClipboardContent),
+    Read(ClipboardFormat),
+}
+
+enum ClipboardMessage {
+    Read(ClipboardRead),
+}
+
+pub struct Clipboard<T> {
+    on_read: Box<dyn FnMut(&mut T, ClipboardRead) -> Action>,
+}
+
+impl<T> Default for Clipboard<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clipboard<T> {
+    pub fn new() -> Self {
+        Self {
+            on_read: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    pub fn on_read<A>(
+        mut self,
+        mut on_read: impl FnMut(&mut T, ClipboardRead) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_read = Box::new(move |data, read| on_read(data, read).into());
+        self
+    }
+}
+
+impl<T> ViewMarker for Clipboard<T> {}
+impl<P, T> View<Context<P>, T> for Clipboard<T>
+where
+    P: HasClipboard + Proxied,
+{
+    type Element = ();
+    type State = ClipboardState<T>;
+
+    fn build(self, _cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let state = ClipboardState {
+            view_id: ViewId::next(),
+            on_read: self.on_read,
+        };
+
+        ((), state)
+    }
+
+    fn rebuild(
+        self,
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        _cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        state.on_read = self.on_read;
+    }
+
+    fn message(
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(ClipboardMessage::Read(read)) = message.take_targeted(state.view_id) {
+            return (state.on_read)(data, read);
+        }
+
+        match message.get::<ClipboardRequest>().cloned() {
+            Some(ClipboardRequest::Write(content)) => {
+                cx.platform.write_clipboard(content);
+            }
+
+            Some(ClipboardRequest::Read(format)) => {
+                let proxy = cx.proxy();
+                let view_id = state.view_id;
+
+                cx.platform.read_clipboard(format.clone(), move |content| {
+                    proxy.message(Message::new(
+                        ClipboardMessage::Read(ClipboardRead { format, content }),
+                        view_id,
+                    ));
+                });
+            }
+
+            None => {}
+        }
+
+        Action::new()
+    }
+
+    fn teardown(_element: Self::Element, _state: Self::State, _cx: &mut Context<P>) {}
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct ClipboardState<T> {
+    view_id: ViewId,
+    on_read: Box<dyn FnMut(&mut T, ClipboardRead) -> Action>,
+}
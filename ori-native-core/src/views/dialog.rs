@@ -0,0 +1,356 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Lifecycle, NativeWidget, Pod, WidgetView,
+    native::{HasAlert, HasDialog, NativeAlert, NativeDialog},
+};
+
+pub fn dialog<V, T>(open: bool, content: V) -> Dialog<V, T> {
+    Dialog::new(open, content)
+}
+
+pub fn alert<T>(
+    title: impl Into<String>,
+    message: impl Into<String>,
+    buttons: impl IntoIterator<Item = impl Into<String>>,
+) -> Alert<T> {
+    Alert::new(title, message, buttons)
+}
+
+// dialogs take no space in the window they belong to
+pub(crate) fn placeholder_style() -> taffy::Style {
+    taffy::Style {
+        display: taffy::Display::None,
+        ..Default::default()
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub struct Dialog<V, T> {
+    open:     bool,
+    content:  V,
+    title:    String,
+    on_close: Box<dyn FnMut(&mut T) -> Action>,
+}
+
+impl<V, T> Dialog<V, T> {
+    pub fn new(open: bool, content: V) -> Self {
+        Self {
+            open,
+            content,
+            title: String::new(),
+            on_close: Box::new(|_| Action::new()),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn on_close<A>(mut self, mut on_close: impl FnMut(&mut T) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_close = Box::new(move |data| on_close(data).into());
+        self
+    }
+}
+
+enum DialogMessage {
+    CloseRequested,
+}
+
+impl<V, T> ViewMarker for Dialog<V, T> {}
+impl<P, T, V> View<Context<P>, T> for Dialog<V, T>
+where
+    P: HasDialog + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P::Dialog>;
+    type State = DialogState<P, T, V>;
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (content, content_state) = self.content.build(cx, data);
+
+        // a dialog is a separate surface, measured like the contents of a window
+        let root = cx.new_layout_node(Default::default(), &[content.node]);
+        let node = cx.new_layout_node(placeholder_style(), &[]);
+
+        let mut widget = P::Dialog::build(
+            &mut cx.platform,
+            content.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_title(&self.title);
+        widget.set_open(self.open);
+        widget.set_on_close_requested({
+            let proxy = cx.proxy();
+
+            move || {
+                proxy.message(Message::new(
+                    DialogMessage::CloseRequested,
+                    view_id,
+                ));
+            }
+        });
+
+        let pod = Pod { node, widget };
+
+        let state = DialogState {
+            view_id,
+            open: self.open,
+            title: self.title,
+            on_close: self.on_close,
+            root,
+            content,
+            content_state,
+        };
+
+        (pod, state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.title != state.title {
+            element.widget.set_title(&self.title);
+            state.title = self.title;
+        }
+
+        if self.open != state.open {
+            element.widget.set_open(self.open);
+            state.open = self.open;
+            cx.relayout();
+        }
+
+        state.on_close = self.on_close;
+
+        (self.content).rebuild(
+            state.content.as_mut(state.root),
+            &mut state.content_state,
+            cx,
+            data,
+        );
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && state.open
+        {
+            let _ = cx.compute_layout(state.root, taffy::Size::max_content());
+
+            if let Ok(layout) = cx.get_computed_layout(state.root) {
+                (element.widget).set_content_size(layout.size.width, layout.size.height);
+            }
+        }
+
+        if let Some(DialogMessage::CloseRequested) = message.take_targeted(state.view_id) {
+            // closing a dialog only closes the dialog, whether it stays closed is up to the data
+            element.widget.set_open(false);
+            state.open = false;
+
+            return (state.on_close)(data);
+        }
+
+        V::message(
+            state.content.as_mut(state.root),
+            &mut state.content_state,
+            cx,
+            data,
+            message,
+        )
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context<P>) {
+        V::teardown(state.content, state.content_state, cx);
+
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(state.root);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct DialogState<P, T, V>
+where
+    P: HasDialog,
+    V: WidgetView<P, T>,
+{
+    view_id:       ViewId,
+    open:          bool,
+    title:         String,
+    on_close:      Box<dyn FnMut(&mut T) -> Action>,
+    root:          taffy::NodeId,
+    content:       Pod<V::Widget>,
+    content_state: V::State,
+}
+
+#[allow(clippy::type_complexity)]
+pub struct Alert<T> {
+    title:       String,
+    message:     String,
+    buttons:     Vec<String>,
+    on_response: Box<dyn FnMut(&mut T, Option<usize>) -> Action>,
+}
+
+impl<T> Alert<T> {
+    pub fn new(
+        title: impl Into<String>,
+        message: impl Into<String>,
+        buttons: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            title:       title.into(),
+            message:     message.into(),
+            buttons:     buttons.into_iter().map(Into::into).collect(),
+            on_response: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    pub fn on_response<A>(
+        mut self,
+        mut on_response: impl FnMut(&mut T, Option<usize>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_response = Box::new(move |data, response| on_response(data, response).into());
+        self
+    }
+}
+
+enum AlertMessage {
+    Responded(Option<usize>),
+}
+
+impl<T> ViewMarker for Alert<T> {}
+impl<P, T> View<Context<P>, T> for Alert<T>
+where
+    P: HasAlert + Proxied,
+{
+    type Element = Pod<P::Alert>;
+    type State = AlertState<T>;
+
+    fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let node = cx.new_layout_node(placeholder_style(), &[]);
+        let mut widget = P::Alert::build(&mut cx.platform);
+
+        let view_id = ViewId::next();
+
+        show::<P>(
+            &mut widget,
+            cx,
+            view_id,
+            &self.title,
+            &self.message,
+            &self.buttons,
+        );
+
+        let pod = Pod { node, widget };
+
+        let state = AlertState {
+            view_id,
+            title: self.title,
+            message: self.message,
+            buttons: self.buttons,
+            on_response: self.on_response,
+        };
+
+        (pod, state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        if self.title != state.title
+            || self.message != state.message
+            || self.buttons != state.buttons
+        {
+            show::<P>(
+                element.widget,
+                cx,
+                state.view_id,
+                &self.title,
+                &self.message,
+                &self.buttons,
+            );
+
+            state.title = self.title;
+            state.message = self.message;
+            state.buttons = self.buttons;
+        }
+
+        state.on_response = self.on_response;
+    }
+
+    fn message(
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        _cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        match message.take_targeted(state.view_id) {
+            Some(AlertMessage::Responded(response)) => (state.on_response)(data, response),
+            None => Action::new(),
+        }
+    }
+
+    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context<P>) {
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+fn show<P>(
+    widget: &mut P::Alert,
+    cx: &mut Context<P>,
+    view_id: ViewId,
+    title: &str,
+    message: &str,
+    buttons: &[String],
+) where
+    P: HasAlert + Proxied,
+{
+    let proxy = cx.proxy();
+
+    widget.show(
+        title,
+        message,
+        buttons,
+        move |response| {
+            proxy.message(Message::new(
+                AlertMessage::Responded(response),
+                view_id,
+            ));
+        },
+    );
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct AlertState<T> {
+    view_id:     ViewId,
+    title:       String,
+    message:     String,
+    buttons:     Vec<String>,
+    on_response: Box<dyn FnMut(&mut T, Option<usize>) -> Action>,
+}
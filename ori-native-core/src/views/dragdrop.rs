@@ -0,0 +1,517 @@
+use std::{any::TypeId, marker::PhantomData, path::PathBuf};
+
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Lifecycle, NativeWidget, Pod, PodMut, Point, WidgetView,
+    native::{
+        DropAccepts, DropData, DropEvent, DropKind, HasDragSource, HasDropTarget, NativeDragSource,
+        NativeDropTarget,
+    },
+};
+
+pub fn drag_source<V, D>(contents: V, payload: D) -> DragSource<V, D> {
+    DragSource::new(contents, payload)
+}
+
+pub fn drop_target<V, T, D, A>(
+    contents: V,
+    on_drop: impl FnMut(&mut T, D, Point<f32>) -> A + 'static,
+) -> DropTarget<V, T, D>
+where
+    A: Into<Action>,
+{
+    DropTarget::new(contents).on_drop(on_drop)
+}
+
+pub struct DragSource<V, D> {
+    contents: V,
+    payload:  D,
+    text:     Option<String>,
+    disabled: bool,
+}
+
+impl<V, D> DragSource<V, D> {
+    pub fn new(contents: V, payload: D) -> Self {
+        Self {
+            contents,
+            payload,
+            text: None,
+            disabled: false,
+        }
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+enum DragSourceMessage {
+    Dragged(bool),
+}
+
+impl<V, D> ViewMarker for DragSource<V, D> {}
+impl<P, T, V, D> View<Context<P>, T> for DragSource<V, D>
+where
+    P: HasDragSource + Proxied,
+    V: WidgetView<P, T>,
+    D: Clone + 'static,
+{
+    type Element = Pod<P::DragSource>;
+    type State = (V::Widget, DragSourceState<P, T, V, D>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::DragSource::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_text(self.text.clone());
+        widget.set_payload_type(TypeId::of::<D>());
+        widget.set_disabled(self.disabled);
+        widget.set_on_drag({
+            let proxy = cx.proxy();
+
+            move |dragging| {
+                proxy.message(Message::new(
+                    DragSourceMessage::Dragged(dragging),
+                    view_id,
+                ));
+            }
+        });
+
+        let pod = Pod {
+            node: contents.node,
+            widget,
+        };
+
+        let state = DragSourceState {
+            view_id,
+            payload: self.payload,
+            text: self.text,
+            disabled: self.disabled,
+            marker: PhantomData,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.text != state.text {
+            element.widget.set_text(self.text.clone());
+            state.text = self.text;
+        }
+
+        if self.disabled != state.disabled {
+            element.widget.set_disabled(self.disabled);
+            state.disabled = self.disabled;
+        }
+
+        state.payload = self.payload;
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        if let Some(DragSourceMessage::Dragged(dragging)) = message.take_targeted(state.view_id) {
+            // in-app payloads never pass through the platform, the context holds the payload of
+            // the drag in progress until the target takes it
+            match dragging && !state.disabled {
+                true => cx.set_drag_payload(Some(Box::new(state.payload.clone()))),
+                false => cx.set_drag_payload(None),
+            }
+
+            return Action::new();
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod {
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+pub struct DragSourceState<P, T, V, D>
+where
+    P: HasDragSource,
+    V: WidgetView<P, T>,
+{
+    view_id:  ViewId,
+    payload:  D,
+    text:     Option<String>,
+    disabled: bool,
+    marker:   PhantomData<fn(&T)>,
+    state:    V::State,
+}
+
+#[allow(clippy::type_complexity)]
+pub struct DropTarget<V, T, D> {
+    contents: V,
+    on_drop:  Option<Box<dyn FnMut(&mut T, D, Point<f32>) -> Action>>,
+    on_files: Option<Box<dyn FnMut(&mut T, Vec<PathBuf>, Point<f32>) -> Action>>,
+    on_uris:  Option<Box<dyn FnMut(&mut T, Vec<String>, Point<f32>) -> Action>>,
+    on_text:  Option<Box<dyn FnMut(&mut T, String, Point<f32>) -> Action>>,
+    on_hover: Box<dyn FnMut(&mut T, bool) -> Action>,
+    disabled: bool,
+}
+
+impl<V, T, D> DropTarget<V, T, D> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            contents,
+            on_drop: None,
+            on_files: None,
+            on_uris: None,
+            on_text: None,
+            on_hover: Box::new(|_, _| Action::new()),
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn on_drop<A>(
+        mut self,
+        mut on_drop: impl FnMut(&mut T, D, Point<f32>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_drop = Some(Box::new(
+            move |data, payload, position| on_drop(data, payload, position).into(),
+        ));
+        self
+    }
+
+    pub fn on_files<A>(
+        mut self,
+        mut on_files: impl FnMut(&mut T, Vec<PathBuf>, Point<f32>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_files = Some(Box::new(
+            move |data, files, position| on_files(data, files, position).into(),
+        ));
+        self
+    }
+
+    pub fn on_uris<A>(
+        mut self,
+        mut on_uris: impl FnMut(&mut T, Vec<String>, Point<f32>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_uris = Some(Box::new(move |data, uris, position| {
+            on_uris(data, uris, position).into()
+        }));
+        self
+    }
+
+    pub fn on_text<A>(
+        mut self,
+        mut on_text: impl FnMut(&mut T, String, Point<f32>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_text = Some(Box::new(move |data, text, position| {
+            on_text(data, text, position).into()
+        }));
+        self
+    }
+
+    pub fn on_hover<A>(mut self, mut on_hover: impl FnMut(&mut T, bool) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_hover = Box::new(move |data, hovered| on_hover(data, hovered).into());
+        self
+    }
+
+    // disabled targets accept nothing, so the platform rejects drops on them, in-app drags are
+    // only accepted when the payload is of the type of the target
+    fn accepts(&self) -> DropAccepts
+    where
+        D: 'static,
+    {
+        let app = !self.disabled && self.on_drop.is_some();
+
+        DropAccepts {
+            app:   app.then(TypeId::of::<D>),
+            files: !self.disabled && (self.on_files.is_some() || self.on_uris.is_some()),
+            text:  !self.disabled && self.on_text.is_some(),
+        }
+    }
+}
+
+enum DropTargetMessage {
+    Dropped(DropEvent),
+}
+
+impl<V, T, D> ViewMarker for DropTarget<V, T, D> {}
+impl<P, T, V, D> View<Context<P>, T> for DropTarget<V, T, D>
+where
+    P: HasDropTarget + Proxied,
+    V: WidgetView<P, T>,
+    D: Clone + 'static,
+{
+    type Element = Pod<P::DropTarget>;
+    type State = (V::Widget, DropTargetState<P, T, V, D>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let accepts = self.accepts();
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::DropTarget::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_accepts(accepts);
+        widget.set_disabled(self.disabled);
+        widget.set_on_drop({
+            let proxy = cx.proxy();
+
+            move |event| {
+                proxy.message(Message::new(
+                    DropTargetMessage::Dropped(event),
+                    view_id,
+                ));
+            }
+        });
+
+        let pod = Pod {
+            node: contents.node,
+            widget,
+        };
+
+        let state = DropTargetState {
+            view_id,
+            accepts,
+            disabled: self.disabled,
+            hovered: false,
+            on_drop: self.on_drop,
+            on_files: self.on_files,
+            on_uris: self.on_uris,
+            on_text: self.on_text,
+            on_hover: self.on_hover,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        let accepts = self.accepts();
+
+        if accepts != state.accepts {
+            element.widget.set_accepts(accepts);
+            state.accepts = accepts;
+        }
+
+        if self.disabled != state.disabled {
+            element.widget.set_disabled(self.disabled);
+            state.disabled = self.disabled;
+        }
+
+        state.on_drop = self.on_drop;
+        state.on_files = self.on_files;
+        state.on_uris = self.on_uris;
+        state.on_text = self.on_text;
+        state.on_hover = self.on_hover;
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        if let Some(DropTargetMessage::Dropped(event)) = message.take_targeted(state.view_id) {
+            return match state.disabled {
+                true => Action::new(),
+                false => dropped(state, cx, data, event),
+            };
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod {
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+fn dropped<P, T, V, D>(
+    state: &mut DropTargetState<P, T, V, D>,
+    cx: &Context<P>,
+    data: &mut T,
+    event: DropEvent,
+) -> Action
+where
+    P: HasDropTarget,
+    V: WidgetView<P, T>,
+    D: Clone + 'static,
+{
+    let mut action = Action::new();
+
+    let hovered = match event {
+        DropEvent::Entered(DropKind::App, _) => payload::<P, D>(cx).is_some(),
+        DropEvent::Entered(_, _) | DropEvent::Moved(_) => true,
+        DropEvent::Left | DropEvent::Dropped(_, _) => false,
+    };
+
+    if hovered != state.hovered && !matches!(event, DropEvent::Moved(_)) {
+        state.hovered = hovered;
+        action |= (state.on_hover)(data, hovered);
+    }
+
+    let DropEvent::Dropped(dropped, position) = event else {
+        return action;
+    };
+
+    match dropped {
+        DropData::App => {
+            if let Some(payload) = payload::<P, D>(cx)
+                && let Some(ref mut on_drop) = state.on_drop
+            {
+                action |= on_drop(data, payload, position);
+            }
+        }
+
+        DropData::Files(files) => {
+            if let Some(ref mut on_uris) = state.on_uris {
+                let uris = files.iter().map(|file| file.uri.clone()).collect();
+                action |= on_uris(data, uris, position);
+            }
+
+            if let Some(ref mut on_files) = state.on_files {
+                let paths = files.into_iter().filter_map(|file| file.path).collect();
+                action |= on_files(data, paths, position);
+            }
+        }
+
+        DropData::Text(text) => {
+            if let Some(ref mut on_text) = state.on_text {
+                action |= on_text(data, text, position);
+            }
+        }
+    }
+
+    action
+}
+
+fn payload<P, D>(cx: &Context<P>) -> Option<D>
+where
+    D: Clone + 'static,
+{
+    cx.drag_payload()?.downcast_ref::<D>().cloned()
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct DropTargetState<P, T, V, D>
+where
+    P: HasDropTarget,
+    V: WidgetView<P, T>,
+{
+    view_id:  ViewId,
+    accepts:  DropAccepts,
+    disabled: bool,
+    hovered:  bool,
+    on_drop:  Option<Box<dyn FnMut(&mut T, D, Point<f32>) -> Action>>,
+    on_files: Option<Box<dyn FnMut(&mut T, Vec<PathBuf>, Point<f32>) -> Action>>,
+    on_uris:  Option<Box<dyn FnMut(&mut T, Vec<String>, Point<f32>) -> Action>>,
+    on_text:  Option<Box<dyn FnMut(&mut T, String, Point<f32>) -> Action>>,
+    on_hover: Box<dyn FnMut(&mut T, bool) -> Action>,
+    state:    V::State,
+}
@@ -0,0 +1,346 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Lifecycle, Modifiers, NativeWidget, Pod, PodMut, Point, WidgetView,
+    native::{DragEvent, DragPhase, HasDraggable, NativeDraggable},
+};
+
+pub fn draggable<V, T>(contents: V) -> Draggable<V, T> {
+    Draggable::new(contents)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DragAxis {
+    Both,
+    Horizontal,
+    Vertical,
+    Dominant,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Drag {
+    pub start:     Point<f32>,
+    pub offset:    Point<f32>,
+    pub delta:     Point<f32>,
+    pub velocity:  Point<f32>,
+    pub modifiers: Modifiers,
+}
+
+#[allow(clippy::type_complexity)]
+pub struct Draggable<V, T> {
+    contents:      V,
+    threshold:     f32,
+    axis:          DragAxis,
+    disabled:      bool,
+    on_drag_start: Box<dyn FnMut(&mut T, Drag) -> Action>,
+    on_drag:       Box<dyn FnMut(&mut T, Drag) -> Action>,
+    on_drag_end:   Box<dyn FnMut(&mut T, Drag) -> Action>,
+}
+
+impl<V, T> Draggable<V, T> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            contents,
+            threshold: 4.0,
+            axis: DragAxis::Both,
+            disabled: false,
+            on_drag_start: Box::new(|_, _| Action::new()),
+            on_drag: Box::new(|_, _| Action::new()),
+            on_drag_end: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn axis(mut self, axis: DragAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn on_drag_start<A>(
+        mut self,
+        mut on_drag_start: impl FnMut(&mut T, Drag) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_drag_start = Box::new(move |data, drag| on_drag_start(data, drag).into());
+        self
+    }
+
+    pub fn on_drag<A>(mut self, mut on_drag: impl FnMut(&mut T, Drag) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_drag = Box::new(move |data, drag| on_drag(data, drag).into());
+        self
+    }
+
+    pub fn on_drag_end<A>(
+        mut self,
+        mut on_drag_end: impl FnMut(&mut T, Drag) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_drag_end = Box::new(move |data, drag| on_drag_end(data, drag).into());
+        self
+    }
+}
+
+enum DraggableMessage {
+    Dragged(DragEvent),
+}
+
+impl<V, T> ViewMarker for Draggable<V, T> {}
+impl<P, T, V> View<Context<P>, T> for Draggable<V, T>
+where
+    P: HasDraggable + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P::Draggable>;
+    type State = (V::Widget, DraggableState<P, T, V>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::Draggable::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_disabled(self.disabled);
+        widget.set_on_drag({
+            let proxy = cx.proxy();
+
+            move |event| {
+                proxy.message(Message::new(
+                    DraggableMessage::Dragged(event),
+                    view_id,
+                ));
+            }
+        });
+
+        let pod = Pod {
+            node: contents.node,
+            widget,
+        };
+
+        let state = DraggableState {
+            view_id,
+            threshold: self.threshold,
+            axis: self.axis,
+            disabled: self.disabled,
+            locked: None,
+            last: None,
+            on_drag_start: self.on_drag_start,
+            on_drag: self.on_drag,
+            on_drag_end: self.on_drag_end,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        // a drag in progress when the draggable gets disabled is dropped without ending it
+        if self.disabled != state.disabled {
+            element.widget.set_disabled(self.disabled);
+            state.disabled = self.disabled;
+
+            if self.disabled {
+                state.locked = None;
+                state.last = None;
+            }
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+
+        state.threshold = self.threshold;
+        state.axis = self.axis;
+        state.on_drag_start = self.on_drag_start;
+        state.on_drag = self.on_drag;
+        state.on_drag_end = self.on_drag_end;
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        if let Some(DraggableMessage::Dragged(event)) = message.take_targeted(state.view_id) {
+            return match state.disabled {
+                true => Action::new(),
+                false => dragged(state, data, event),
+            };
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod {
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+fn dragged<P, T, V>(state: &mut DraggableState<P, T, V>, data: &mut T, event: DragEvent) -> Action
+where
+    P: HasDraggable,
+    V: WidgetView<P, T>,
+{
+    match event.phase {
+        DragPhase::Began => {
+            state.locked = None;
+            state.last = None;
+            Action::new()
+        }
+
+        DragPhase::Moved => {
+            let Some(last) = state.last else {
+                // the drag only starts once it has moved past the threshold, along the locked
+                // axis if there is one, so that presses and scrolls aren't mistaken for drags
+                let axis = match state.axis {
+                    DragAxis::Dominant if event.offset.x.abs() >= event.offset.y.abs() => {
+                        DragAxis::Horizontal
+                    }
+                    DragAxis::Dominant => DragAxis::Vertical,
+                    axis => axis,
+                };
+
+                let offset = lock(axis, event.offset);
+
+                if offset.x.hypot(offset.y) < state.threshold {
+                    return Action::new();
+                }
+
+                state.locked = Some(axis);
+                state.last = Some(offset);
+
+                let drag = state.drag(event, offset, offset);
+                return (state.on_drag_start)(data, drag);
+            };
+
+            let offset = state.offset(event.offset);
+            state.last = Some(offset);
+
+            let delta = Point {
+                x: offset.x - last.x,
+                y: offset.y - last.y,
+            };
+
+            let drag = state.drag(event, offset, delta);
+            (state.on_drag)(data, drag)
+        }
+
+        DragPhase::Ended | DragPhase::Cancelled => {
+            let Some(last) = state.last.take() else {
+                return Action::new();
+            };
+
+            let offset = state.offset(event.offset);
+
+            let delta = Point {
+                x: offset.x - last.x,
+                y: offset.y - last.y,
+            };
+
+            let mut drag = state.drag(event, offset, delta);
+
+            if let DragPhase::Cancelled = event.phase {
+                drag.velocity = Point { x: 0.0, y: 0.0 };
+            }
+
+            state.locked = None;
+            (state.on_drag_end)(data, drag)
+        }
+    }
+}
+
+fn lock(axis: DragAxis, point: Point<f32>) -> Point<f32> {
+    match axis {
+        DragAxis::Horizontal => Point { x: point.x, y: 0.0 },
+        DragAxis::Vertical => Point { x: 0.0, y: point.y },
+        DragAxis::Both | DragAxis::Dominant => point,
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct DraggableState<P, T, V>
+where
+    P: HasDraggable,
+    V: WidgetView<P, T>,
+{
+    view_id:       ViewId,
+    threshold:     f32,
+    axis:          DragAxis,
+    disabled:      bool,
+    locked:        Option<DragAxis>,
+    last:          Option<Point<f32>>,
+    on_drag_start: Box<dyn FnMut(&mut T, Drag) -> Action>,
+    on_drag:       Box<dyn FnMut(&mut T, Drag) -> Action>,
+    on_drag_end:   Box<dyn FnMut(&mut T, Drag) -> Action>,
+    state:         V::State,
+}
+
+impl<P, T, V> DraggableState<P, T, V>
+where
+    P: HasDraggable,
+    V: WidgetView<P, T>,
+{
+    fn offset(&self, offset: Point<f32>) -> Point<f32> {
+        lock(self.locked.unwrap_or(self.axis), offset)
+    }
+
+    fn drag(&self, event: DragEvent, offset: Point<f32>, delta: Point<f32>) -> Drag {
+        Drag {
+            start: event.start,
+            offset,
+            delta,
+            velocity: self.offset(event.velocity),
+            modifiers: event.modifiers,
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use std::path::PathBuf;
+
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Pod,
+    native::{
+        FileDialogMode, FileDialogOptions, FileFilter, HasFileDialog, NativeFileDialog,
+        SelectedFile,
+    },
+    views::dialog::placeholder_style,
+};
+
+pub fn open_file<T>() -> FileDialog<T> {
+    FileDialog::new(FileDialogMode::Open)
+}
+
+pub fn open_files<T>() -> FileDialog<T> {
+    FileDialog::new(FileDialogMode::OpenMultiple)
+}
+
+pub fn save_file<T>() -> FileDialog<T> {
+    FileDialog::new(FileDialogMode::Save)
+}
+
+pub fn select_folder<T>() -> FileDialog<T> {
+    FileDialog::new(FileDialogMode::Folder)
+}
+
+#[allow(clippy::type_complexity)]
+pub struct FileDialog<T> {
+    options:   FileDialogOptions,
+    on_select: Box<dyn FnMut(&mut T, Vec<PathBuf>) -> Action>,
+    on_uris:   Box<dyn FnMut(&mut T, Vec<String>) -> Action>,
+    on_cancel: Box<dyn FnMut(&mut T) -> Action>,
+}
+
+impl<T> FileDialog<T> {
+    pub fn new(mode: FileDialogMode) -> Self {
+        Self {
+            options:   FileDialogOptions {
+                mode,
+                title: None,
+                filters: Vec::new(),
+                directory: None,
+                name: None,
+            },
+            on_select: Box::new(|_, _| Action::new()),
+            on_uris:   Box::new(|_, _| Action::new()),
+            on_cancel: Box::new(|_| Action::new()),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.options.title = Some(title.into());
+        self
+    }
+
+    pub fn filter(mut self, filter: FileFilter) -> Self {
+        self.options.filters.push(filter);
+        self
+    }
+
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.options.directory = Some(directory.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.options.name = Some(name.into());
+        self
+    }
+
+    // files without a local path, like remote locations, are left out of the paths, but are
+    // still passed to `on_uris`
+    pub fn on_select<A>(
+        mut self,
+        mut on_select: impl FnMut(&mut T, Vec<PathBuf>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_select = Box::new(move |data, paths| on_select(data, paths).into());
+        self
+    }
+
+    pub fn on_uris<A>(mut self, mut on_uris: impl FnMut(&mut T, Vec<String>) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_uris = Box::new(move |data, uris| on_uris(data, uris).into());
+        self
+    }
+
+    pub fn on_cancel<A>(mut self, mut on_cancel: impl FnMut(&mut T) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_cancel = Box::new(move |data| on_cancel(data).into());
+        self
+    }
+}
+
+enum FileDialogMessage {
+    Result(Option<Vec<SelectedFile>>),
+}
+
+impl<T> ViewMarker for FileDialog<T> {}
+impl<P, T> View<Context<P>, T> for FileDialog<T>
+where
+    P: HasFileDialog + Proxied,
+{
+    type Element = Pod<P::FileDialog>;
+    type State = FileDialogState<T>;
+
+    fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let node = cx.new_layout_node(placeholder_style(), &[]);
+        let mut widget = P::FileDialog::build(&mut cx.platform);
+
+        let view_id = ViewId::next();
+
+        show::<P>(&mut widget, cx, view_id, &self.options);
+
+        let pod = Pod { node, widget };
+
+        let state = FileDialogState {
+            view_id,
+            options: self.options,
+            on_select: self.on_select,
+            on_uris: self.on_uris,
+            on_cancel: self.on_cancel,
+        };
+
+        (pod, state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        if self.options != state.options {
+            show::<P>(
+                element.widget,
+                cx,
+                state.view_id,
+                &self.options,
+            );
+            state.options = self.options;
+        }
+
+        state.on_select = self.on_select;
+        state.on_uris = self.on_uris;
+        state.on_cancel = self.on_cancel;
+    }
+
+    fn message(
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        _cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        match message.take_targeted(state.view_id) {
+            Some(FileDialogMessage::Result(Some(files))) => {
+                let uris = files.iter().map(|file| file.uri.clone()).collect();
+                let mut action = (state.on_uris)(data, uris);
+
+                let paths = files.into_iter().filter_map(|file| file.path).collect();
+                action |= (state.on_select)(data, paths);
+
+                action
+            }
+
+            Some(FileDialogMessage::Result(None)) => (state.on_cancel)(data),
+            None => Action::new(),
+        }
+    }
+
+    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context<P>) {
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+fn show<P>(
+    widget: &mut P::FileDialog,
+    cx: &mut Context<P>,
+    view_id: ViewId,
+    options: &FileDialogOptions,
+) where
+    P: HasFileDialog + Proxied,
+{
+    let proxy = cx.proxy();
+
+    widget.show(options, move |result| {
+        proxy.message(Message::new(
+            FileDialogMessage::Result(result),
+            view_id,
+        ));
+    });
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct FileDialogState<T> {
+    view_id:   ViewId,
+    options:   FileDialogOptions,
+    on_select: Box<dyn FnMut(&mut T, Vec<PathBuf>) -> Action>,
+    on_uris:   Box<dyn FnMut(&mut T, Vec<String>) -> Action>,
+    on_cancel: Box<dyn FnMut(&mut T) -> Action>,
+}
@@ -0,0 +1,345 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Lifecycle, NativeWidget, Platform, Pod, PodMut, WidgetView,
+    native::{HasFocusTarget, NativeFocusTarget},
+};
+
+pub fn focus_target<V, T>(contents: V) -> FocusTarget<V, T> {
+    FocusTarget::new(contents)
+}
+
+pub fn request_focus(id: impl Into<String>) -> Message {
+    Message::new(RequestFocus(id.into()), None)
+}
+
+pub fn watch_focus<V, T, A>(
+    contents: V,
+    on_change: impl FnMut(&mut T, Option<&str>) -> A + 'static,
+) -> WatchFocus<V, T>
+where
+    A: Into<Action>,
+{
+    WatchFocus::new(contents, on_change)
+}
+
+struct RequestFocus(String);
+
+#[allow(clippy::type_complexity)]
+pub struct FocusTarget<V, T> {
+    contents:  V,
+    id:        Option<String>,
+    autofocus: bool,
+    trap:      bool,
+    tab_index: i32,
+    on_focus:  Box<dyn FnMut(&mut T, bool) -> Action>,
+}
+
+impl<V, T> FocusTarget<V, T> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            contents,
+            id: None,
+            autofocus: false,
+            trap: false,
+            tab_index: 0,
+            on_focus: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.autofocus = autofocus;
+        self
+    }
+
+    pub fn trap(mut self, trap: bool) -> Self {
+        self.trap = trap;
+        self
+    }
+
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    pub fn on_focus<A>(mut self, mut on_focus: impl FnMut(&mut T, bool) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_focus = Box::new(move |data, focused| on_focus(data, focused).into());
+        self
+    }
+}
+
+enum FocusMessage {
+    Focused(bool),
+}
+
+impl<V, T> ViewMarker for FocusTarget<V, T> {}
+impl<P, T, V> View<Context<P>, T> for FocusTarget<V, T>
+where
+    P: HasFocusTarget + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P::FocusTarget>;
+    type State = (V::Widget, FocusTargetState<P, T, V>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::FocusTarget::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_tab_index(self.tab_index);
+        widget.set_trap(self.trap);
+        widget.set_on_focus({
+            let proxy = cx.proxy();
+
+            move |focused| {
+                proxy.message(Message::new(
+                    FocusMessage::Focused(focused),
+                    view_id,
+                ));
+            }
+        });
+
+        if self.autofocus {
+            widget.focus();
+        }
+
+        let pod = Pod {
+            node: contents.node,
+            widget,
+        };
+
+        let state = FocusTargetState {
+            view_id,
+            id: self.id,
+            trap: self.trap,
+            tab_index: self.tab_index,
+            focused: false,
+            on_focus: self.on_focus,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.tab_index != state.tab_index {
+            element.widget.set_tab_index(self.tab_index);
+            state.tab_index = self.tab_index;
+        }
+
+        if self.trap != state.trap {
+            element.widget.set_trap(self.trap);
+            state.trap = self.trap;
+
+            // a trap that isn't holding focus wouldn't trap anything
+            if self.trap && !state.focused {
+                element.widget.focus();
+            }
+        }
+
+        // keep the reported id in step when a focused target is renamed
+        if state.focused && cx.focused() == state.id.as_deref() {
+            cx.set_focused(self.id.clone());
+        }
+
+        state.id = self.id;
+        state.on_focus = self.on_focus;
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        if let Some(RequestFocus(id)) = message.get()
+            && state.id.as_ref() == Some(id)
+        {
+            element.widget.focus();
+            return Action::new();
+        }
+
+        if let Some(FocusMessage::Focused(focused)) = message.take_targeted(state.view_id) {
+            if focused == state.focused {
+                return Action::new();
+            }
+
+            state.focused = focused;
+
+            // the id of the focused target is what watch_focus reports
+            if focused {
+                cx.set_focused(state.id.clone());
+            } else if cx.focused() == state.id.as_deref() {
+                cx.set_focused(None);
+            }
+
+            return (state.on_focus)(data, focused);
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod {
+            node:   element.node,
+            widget: contents,
+        };
+
+        if state.focused && cx.focused() == state.id.as_deref() {
+            cx.set_focused(None);
+        }
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct FocusTargetState<P, T, V>
+where
+    P: HasFocusTarget,
+    V: WidgetView<P, T>,
+{
+    view_id:   ViewId,
+    id:        Option<String>,
+    trap:      bool,
+    tab_index: i32,
+    focused:   bool,
+    on_focus:  Box<dyn FnMut(&mut T, bool) -> Action>,
+    state:     V::State,
+}
+
+#[allow(clippy::type_complexity)]
+pub struct WatchFocus<V, T> {
+    contents:  V,
+    on_change: Box<dyn FnMut(&mut T, Option<&str>) -> Action>,
+}
+
+impl<V, T> WatchFocus<V, T> {
+    pub fn new<A>(
+        contents: V,
+        mut on_change: impl FnMut(&mut T, Option<&str>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self {
+            contents,
+            on_change: Box::new(move |data, id| on_change(data, id).into()),
+        }
+    }
+}
+
+impl<V, T> ViewMarker for WatchFocus<V, T> {}
+impl<P, T, V> View<Context<P>, T> for WatchFocus<V, T>
+where
+    P: Platform,
+    V: WidgetView<P, T>,
+{
+    type Element = V::Element;
+    type State = WatchFocusState<P, T, V>;
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (element, state) = self.contents.build(cx, data);
+
+        let state = WatchFocusState {
+            focused: cx.focused().map(String::from),
+            on_change: self.on_change,
+            state,
+        };
+
+        (element, state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        state.on_change = self.on_change;
+        self.contents.rebuild(element, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        let mut action = V::message(
+            element,
+            &mut state.state,
+            cx,
+            data,
+            message,
+        );
+
+        // focus targets record their id in the context when they gain focus
+        if cx.focused() != state.focused.as_deref() {
+            state.focused = cx.focused().map(String::from);
+            action |= (state.on_change)(data, state.focused.as_deref());
+        }
+
+        action
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context<P>) {
+        V::teardown(element, state.state, cx);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct WatchFocusState<P, T, V>
+where
+    P: Platform,
+    V: WidgetView<P, T>,
+{
+    focused:   Option<String>,
+    on_change: Box<dyn FnMut(&mut T, Option<&str>) -> Action>,
+    state:     V::State,
+}
@@ -0,0 +1,336 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Lifecycle, NativeWidget, Pod, PodMut, Point, WidgetView,
+    native::{HasGesture, NativeGesture, RotateEvent, ZoomEvent},
+};
+
+pub fn gesture<V, T>(contents: V) -> Gesture<V, T> {
+    Gesture::new(contents)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Swipe {
+    pub direction: SwipeDirection,
+    pub velocity:  Point<f32>,
+}
+
+#[allow(clippy::type_complexity)]
+pub struct Gesture<V, T> {
+    contents:        V,
+    swipe_threshold: f32,
+    disabled:        bool,
+    on_zoom:         Box<dyn FnMut(&mut T, ZoomEvent) -> Action>,
+    on_rotate:       Box<dyn FnMut(&mut T, RotateEvent) -> Action>,
+    on_swipe:        Box<dyn FnMut(&mut T, Swipe) -> Action>,
+}
+
+impl<V, T> Gesture<V, T> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            contents,
+            swipe_threshold: 200.0,
+            disabled: false,
+            on_zoom: Box::new(|_, _| Action::new()),
+            on_rotate: Box::new(|_, _| Action::new()),
+            on_swipe: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    pub fn swipe_threshold(mut self, velocity: f32) -> Self {
+        self.swipe_threshold = velocity;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn on_zoom<A>(mut self, mut on_zoom: impl FnMut(&mut T, ZoomEvent) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_zoom = Box::new(move |data, zoom| on_zoom(data, zoom).into());
+        self
+    }
+
+    pub fn on_rotate<A>(
+        mut self,
+        mut on_rotate: impl FnMut(&mut T, RotateEvent) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_rotate = Box::new(move |data, rotate| on_rotate(data, rotate).into());
+        self
+    }
+
+    pub fn on_swipe<A>(mut self, mut on_swipe: impl FnMut(&mut T, Swipe) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_swipe = Box::new(move |data, swipe| on_swipe(data, swipe).into());
+        self
+    }
+}
+
+enum GestureMessage {
+    Zoomed(ZoomEvent),
+    Rotated(RotateEvent),
+    Swiped(Point<f32>),
+}
+
+impl<V, T> ViewMarker for Gesture<V, T> {}
+impl<P, T, V> View<Context<P>, T> for Gesture<V, T>
+where
+    P: HasGesture + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P::Gesture>;
+    type State = (V::Widget, GestureState<P, T, V>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::Gesture::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_disabled(self.disabled);
+        widget.set_on_zoom({
+            let proxy = cx.proxy();
+
+            move |zoom| {
+                proxy.message(Message::new(
+                    GestureMessage::Zoomed(zoom),
+                    view_id,
+                ));
+            }
+        });
+
+        widget.set_on_rotate({
+            let proxy = cx.proxy();
+
+            move |rotate| {
+                proxy.message(Message::new(
+                    GestureMessage::Rotated(rotate),
+                    view_id,
+                ));
+            }
+        });
+
+        widget.set_on_swipe({
+            let proxy = cx.proxy();
+
+            move |velocity| {
+                proxy.message(Message::new(
+                    GestureMessage::Swiped(velocity),
+                    view_id,
+                ));
+            }
+        });
+
+        let pod = Pod {
+            node: contents.node,
+            widget,
+        };
+
+        let state = GestureState {
+            view_id,
+            swipe_threshold: self.swipe_threshold,
+            disabled: self.disabled,
+            on_zoom: self.on_zoom,
+            on_rotate: self.on_rotate,
+            on_swipe: self.on_swipe,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.disabled != state.disabled {
+            element.widget.set_disabled(self.disabled);
+            state.disabled = self.disabled;
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+
+        state.swipe_threshold = self.swipe_threshold;
+        state.on_zoom = self.on_zoom;
+        state.on_rotate = self.on_rotate;
+        state.on_swipe = self.on_swipe;
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        if let Some(message) = message.take_targeted(state.view_id) {
+            return match message {
+                _ if state.disabled => Action::new(),
+
+                GestureMessage::Zoomed(zoom) => (state.on_zoom)(data, zoom),
+                GestureMessage::Rotated(rotate) => (state.on_rotate)(data, rotate),
+
+                GestureMessage::Swiped(velocity) => match swipe_direction(velocity) {
+                    Some(direction) if velocity.x.hypot(velocity.y) >= state.swipe_threshold => {
+                        let swipe = Swipe {
+                            direction,
+                            velocity,
+                        };
+
+                        (state.on_swipe)(data, swipe)
+                    }
+
+                    _ => Action::new(),
+                },
+            };
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod {
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+fn swipe_direction(velocity: Point<f32>) -> Option<SwipeDirection> {
+    if velocity.x == 0.0 && velocity.y == 0.0 {
+        return None;
+    }
+
+    // the direction is that of the dominant axis
+    Some(
+        match velocity.x.abs() >= velocity.y.abs() {
+            true if velocity.x < 0.0 => SwipeDirection::Left,
+            true => SwipeDirection::Right,
+            false if velocity.y < 0.0 => SwipeDirection::Up,
+            false => SwipeDirection::Down,
+        },
+    )
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct GestureState<P, T, V>
+where
+    P: HasGesture,
+    V: WidgetView<P, T>,
+{
+    view_id:         ViewId,
+    swipe_threshold: f32,
+    disabled:        bool,
+    on_zoom:         Box<dyn FnMut(&mut T, ZoomEvent) -> Action>,
+    on_rotate:       Box<dyn FnMut(&mut T, RotateEvent) -> Action>,
+    on_swipe:        Box<dyn FnMut(&mut T, Swipe) -> Action>,
+    state:           V::State,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn direction(x: f32, y: f32) -> Option<SwipeDirection> {
+        swipe_direction(Point { x, y })
+    }
+
+    #[test]
+    fn swipe_direction_still() {
+        assert_eq!(direction(0.0, 0.0), None);
+    }
+
+    #[test]
+    fn swipe_direction_axes() {
+        assert_eq!(
+            direction(-10.0, 0.0),
+            Some(SwipeDirection::Left)
+        );
+        assert_eq!(
+            direction(10.0, 0.0),
+            Some(SwipeDirection::Right)
+        );
+        assert_eq!(
+            direction(0.0, -10.0),
+            Some(SwipeDirection::Up)
+        );
+        assert_eq!(
+            direction(0.0, 10.0),
+            Some(SwipeDirection::Down)
+        );
+    }
+
+    #[test]
+    fn swipe_direction_dominant_axis() {
+        assert_eq!(
+            direction(-10.0, 4.0),
+            Some(SwipeDirection::Left)
+        );
+        assert_eq!(
+            direction(3.0, -12.0),
+            Some(SwipeDirection::Up)
+        );
+        assert_eq!(
+            direction(-5.0, 20.0),
+            Some(SwipeDirection::Down)
+        );
+    }
+
+    #[test]
+    fn swipe_direction_diagonal_is_horizontal() {
+        assert_eq!(
+            direction(5.0, 5.0),
+            Some(SwipeDirection::Right)
+        );
+        assert_eq!(
+            direction(-5.0, -5.0),
+            Some(SwipeDirection::Left)
+        );
+    }
+}
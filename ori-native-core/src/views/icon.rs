@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+
+use ori::{Action, Message, Mut, View, ViewMarker};
+
+use crate::{
+    Color, Context, Layout, Pod,
+    native::{HasImage, NativeImage},
+};
+
+pub fn icon(name: impl Into<Cow<'static, str>>) -> Icon {
+    Icon::new(name)
+}
+
+pub struct Icon {
+    style: taffy::Style,
+    name:  Cow<'static, str>,
+    size:  u32,
+    scale: Option<u32>,
+    tint:  Option<Color>,
+}
+
+impl Icon {
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            style: Default::default(),
+            name:  name.into(),
+            size:  16,
+            scale: None,
+            tint:  None,
+        }
+    }
+
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn scale(mut self, scale: impl Into<Option<u32>>) -> Self {
+        self.scale = scale.into();
+        self
+    }
+
+    pub fn tint(mut self, tint: impl Into<Option<Color>>) -> Self {
+        self.tint = tint.into();
+        self
+    }
+}
+
+impl Layout for Icon {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        &mut self.style
+    }
+}
+
+impl ViewMarker for Icon {}
+impl<P, T> View<Context<P>, T> for Icon
+where
+    P: HasImage,
+{
+    type Element = Pod<P::Image>;
+    type State = (
+        Cow<'static, str>,
+        u32,
+        Option<u32>,
+        Option<Color>,
+    );
+
+    fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        let mut widget = P::Image::build(&mut cx.platform);
+        widget.set_tint(self.tint);
+
+        let layout = widget
+            .load_icon(
+                &mut cx.platform,
+                &self.name,
+                self.size,
+                self.scale,
+            )
+            .unwrap();
+
+        let node = cx.new_layout_leaf(self.style, layout);
+
+        let pod = Pod { node, widget };
+
+        (
+            pod,
+            (
+                self.name, self.size, self.scale, self.tint,
+            ),
+        )
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (name, size, scale, tint): &mut Self::State,
+        cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        let _ = cx.set_layout_style(*element.node, self.style);
+
+        if self.tint != *tint {
+            element.widget.set_tint(self.tint);
+            *tint = self.tint;
+        }
+
+        if self.name == *name && self.size == *size && self.scale == *scale {
+            return;
+        }
+
+        let layout = element.widget.load_icon(
+            &mut cx.platform,
+            &self.name,
+            self.size,
+            self.scale,
+        );
+
+        if let Ok(layout) = layout {
+            let _ = cx.set_leaf_layout(*element.node, layout);
+        }
+
+        *name = self.name;
+        *size = self.size;
+        *scale = self.scale;
+    }
+
+    fn message(
+        _element: Mut<'_, Self::Element>,
+        _state: &mut Self::State,
+        _cx: &mut Context<P>,
+        _data: &mut T,
+        _message: &mut Message,
+    ) -> Action {
+        Action::new()
+    }
+
+    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context<P>) {
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
@@ -1,28 +1,92 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc, time::Duration};
 
+#[cfg(feature = "animated-images")]
+use image_rs::{
+    AnimationDecoder,
+    codecs::{gif::GifDecoder, png::PngDecoder},
+};
 use ori::{Action, Message, Mut, View, ViewMarker};
 
 use crate::{
-    Color, Context, Layout, Pod,
-    native::{HasImage, NativeImage},
+    Color, Context, Layout, Lifecycle, Pod,
+    native::{HasImage, NativeImage, SvgOptions, ViewBox},
+    views::{AnimationFrame, ScrollViewport},
 };
 
 pub fn image(data: impl Into<Cow<'static, [u8]>>) -> Image {
     Image::new(data.into())
 }
 
+pub fn image_rgba(width: u32, height: u32, pixels: impl Into<Arc<[u8]>>) -> Image {
+    Image::rgba(width, height, pixels.into())
+}
+
 pub struct Image {
-    style: taffy::Style,
-    data:  Cow<'static, [u8]>,
-    tint:  Option<Color>,
+    style:      taffy::Style,
+    source:     ImageSource,
+    tint:       Option<Color>,
+    playing:    bool,
+    loop_count: Option<u32>,
+    svg:        SvgOptions,
+}
+
+#[derive(Clone, Debug)]
+enum ImageSource {
+    Data(Cow<'static, [u8]>),
+    Rgba {
+        width:  u32,
+        height: u32,
+        pixels: Arc<[u8]>,
+    },
+}
+
+// pixel buffers are compared by identity, comparing the contents would cost a pass over the whole
+// buffer on every rebuild
+impl PartialEq for ImageSource {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            // skip comparing the bytes when both point at the same data
+            (Self::Data(a), Self::Data(b)) => std::ptr::eq(a.as_ref(), b.as_ref()) || a == b,
+
+            (
+                Self::Rgba {
+                    width: aw,
+                    height: ah,
+                    pixels: a,
+                },
+                Self::Rgba {
+                    width: bw,
+                    height: bh,
+                    pixels: b,
+                },
+            ) => aw == bw && ah == bh && Arc::ptr_eq(a, b),
+
+            _ => false,
+        }
+    }
 }
 
 impl Image {
     pub fn new(data: Cow<'static, [u8]>) -> Self {
+        Self::with_source(ImageSource::Data(data))
+    }
+
+    pub fn rgba(width: u32, height: u32, pixels: Arc<[u8]>) -> Self {
+        Self::with_source(ImageSource::Rgba {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    fn with_source(source: ImageSource) -> Self {
         Self {
             style: Default::default(),
-            data,
+            source,
             tint: None,
+            playing: true,
+            loop_count: None,
+            svg: SvgOptions::default(),
         }
     }
 
@@ -30,6 +94,36 @@ impl Image {
         self.tint = tint.into();
         self
     }
+
+    pub fn playing(mut self, playing: bool) -> Self {
+        self.playing = playing;
+        self
+    }
+
+    pub fn loop_count(mut self, loop_count: impl Into<Option<u32>>) -> Self {
+        self.loop_count = loop_count.into();
+        self
+    }
+
+    pub fn view_box(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.svg.view_box = Some(ViewBox {
+            x,
+            y,
+            width,
+            height,
+        });
+        self
+    }
+
+    pub fn stylesheet(mut self, stylesheet: impl Into<Cow<'static, str>>) -> Self {
+        self.svg.stylesheet = Some(stylesheet.into());
+        self
+    }
+
+    pub fn current_color(mut self, color: impl Into<Option<Color>>) -> Self {
+        self.svg.current_color = color.into();
+        self
+    }
 }
 
 impl Layout for Image {
@@ -44,42 +138,477 @@ where
     P: HasImage,
 {
     type Element = Pod<P::Image>;
-    type State = ();
+    type State = ImageState;
 
     fn build(self, cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
         let mut widget = P::Image::build(&mut cx.platform);
         widget.set_tint(self.tint);
+        widget.set_svg_options(&self.svg);
+
+        let animation = match self.source {
+            ImageSource::Data(ref data) => Animation::decode(data),
+            ImageSource::Rgba { .. } => None,
+        };
+
+        let mut loaded = true;
+
+        let node = match (&animation, self.source.clone()) {
+            (Some(animation), _) => {
+                let layout = widget
+                    .load_rgba(
+                        &mut cx.platform,
+                        animation.width,
+                        animation.height,
+                        animation.current(),
+                    )
+                    .unwrap();
+
+                cx.new_layout_leaf(self.style, layout)
+            }
 
-        let layout = widget.load_data(&mut cx.platform, self.data).unwrap();
+            (None, ImageSource::Data(data)) => {
+                let layout = widget.load_data(&mut cx.platform, data).unwrap();
+                cx.new_layout_leaf(self.style, layout)
+            }
 
-        let node = cx.new_layout_leaf(self.style, layout);
+            (
+                None,
+                ImageSource::Rgba {
+                    width,
+                    height,
+                    pixels,
+                },
+            ) => match widget.load_rgba(&mut cx.platform, width, height, pixels) {
+                Ok(layout) => cx.new_layout_leaf(self.style, layout),
+                Err(err) => {
+                    tracing::warn!("failed to load rgba image: {err}");
+                    loaded = false;
+                    cx.new_layout_node(self.style, &[])
+                }
+            },
+        };
 
         let pod = Pod { node, widget };
 
-        (pod, ())
+        let state = ImageState {
+            source: self.source,
+            tint: self.tint,
+            playing: self.playing,
+            loop_count: self.loop_count,
+            svg: self.svg,
+            loaded,
+            animation,
+        };
+
+        (pod, state)
     }
 
     fn rebuild(
         self,
         element: Mut<'_, Self::Element>,
-        _state: &mut Self::State,
+        state: &mut Self::State,
         cx: &mut Context<P>,
         _data: &mut T,
     ) {
         let _ = cx.set_layout_style(*element.node, self.style);
+
+        if self.tint != state.tint {
+            element.widget.set_tint(self.tint);
+            state.tint = self.tint;
+        }
+
+        if let Some(ref mut animation) = state.animation
+            && self.playing
+            && !state.playing
+            && animation.is_finished(self.loop_count)
+        {
+            animation.restart();
+        }
+
+        state.playing = self.playing;
+        state.loop_count = self.loop_count;
+
+        if self.svg != state.svg {
+            let resized = self.svg.view_box != state.svg.view_box;
+
+            element.widget.set_svg_options(&self.svg);
+            state.svg = self.svg;
+
+            // the view box determines the size of the svg, so it has to be measured again
+            if resized && self.source == state.source {
+                let _ = cx.mark_layout_dirty(*element.node);
+            }
+        }
+
+        if self.source != state.source {
+            if let Some(animation) = state.animation.take() {
+                animation.stop(cx);
+            }
+
+            let animation = match self.source {
+                ImageSource::Data(ref data) => Animation::decode(data),
+                ImageSource::Rgba { .. } => None,
+            };
+
+            match (&animation, self.source.clone()) {
+                (Some(animation), _) => {
+                    let layout = element.widget.load_rgba(
+                        &mut cx.platform,
+                        animation.width,
+                        animation.height,
+                        animation.current(),
+                    );
+
+                    if let Ok(layout) = layout {
+                        let _ = cx.set_leaf_layout(*element.node, layout);
+                        state.loaded = true;
+                    }
+                }
+
+                (None, ImageSource::Data(data)) => {
+                    if let Ok(layout) = element.widget.load_data(&mut cx.platform, data) {
+                        let _ = cx.set_leaf_layout(*element.node, layout);
+                        state.loaded = true;
+                    }
+                }
+
+                (
+                    None,
+                    ImageSource::Rgba {
+                        width,
+                        height,
+                        pixels,
+                    },
+                ) => {
+                    let resized = !matches!(
+                        state.source,
+                        ImageSource::Rgba {
+                            width: w,
+                            height: h,
+                            ..
+                        } if w == width && h == height
+                    );
+
+                    let layout = element
+                        .widget
+                        .load_rgba(&mut cx.platform, width, height, pixels);
+
+                    // only relayout when the size of the image actually changed, or when the
+                    // node has no measured leaf because the previous load failed
+                    match layout {
+                        Ok(layout) if resized || !state.loaded => {
+                            let _ = cx.set_leaf_layout(*element.node, layout);
+                            state.loaded = true;
+                        }
+                        Ok(_) => {}
+                        Err(err) => {
+                            tracing::warn!("failed to load rgba image: {err}");
+                            state.loaded = false;
+                        }
+                    }
+                }
+            }
+
+            state.source = self.source;
+            state.animation = animation;
+        }
+
+        if let Some(ref mut animation) = state.animation {
+            animation.update(cx, state.playing, state.loop_count);
+        }
     }
 
     fn message(
-        _element: Mut<'_, Self::Element>,
-        _state: &mut Self::State,
-        _cx: &mut Context<P>,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
         _data: &mut T,
-        _message: &mut Message,
+        message: &mut Message,
     ) -> Action {
+        let Some(ref mut animation) = state.animation else {
+            return Action::new();
+        };
+
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            animation.sized = layout.size.width > 0.0 && layout.size.height > 0.0;
+            animation.update(cx, state.playing, state.loop_count);
+        }
+
+        // inside a scroll, frames are only advanced while the image intersects the viewport
+        if let Some(viewport) = message.get::<ScrollViewport>()
+            && let Some((x, y)) = viewport.offset_of(cx, *element.node)
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            animation.in_viewport = x < viewport.x + viewport.width
+                && y < viewport.y + viewport.height
+                && x + layout.size.width > viewport.x
+                && y + layout.size.height > viewport.y;
+
+            animation.update(cx, state.playing, state.loop_count);
+        }
+
+        if let Some(AnimationFrame(delta)) = message.get()
+            && animation.animating
+        {
+            if animation.advance(*delta, state.loop_count) {
+                let _ = element.widget.load_rgba(
+                    &mut cx.platform,
+                    animation.width,
+                    animation.height,
+                    animation.current(),
+                );
+            }
+
+            animation.update(cx, state.playing, state.loop_count);
+        }
+
         Action::new()
     }
 
-    fn teardown(element: Self::Element, _state: Self::State, cx: &mut Context<P>) {
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context<P>) {
+        if let Some(animation) = state.animation {
+            animation.stop(cx);
+        }
+
         element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+#[doc(hidden)]
+pub struct ImageState {
+    source:     ImageSource,
+    tint:       Option<Color>,
+    playing:    bool,
+    loop_count: Option<u32>,
+    svg:        SvgOptions,
+    loaded:     bool,
+    animation:  Option<Animation>,
+}
+
+struct Animation {
+    width:       u32,
+    height:      u32,
+    frames:      Vec<AnimationFrameData>,
+    index:       usize,
+    elapsed:     Duration,
+    loops:       u32,
+    sized:       bool,
+    in_viewport: bool,
+    animating:   bool,
+}
+
+#[cfg(feature = "animated-images")]
+fn decode_frames(data: &[u8]) -> Option<(u32, u32, Vec<AnimationFrameData>)> {
+    let cursor = std::io::Cursor::new(data);
+
+    let frames = if data.starts_with(b"GIF8") {
+        let decoder = GifDecoder::new(cursor).ok()?;
+        decoder.into_frames().collect_frames().ok()?
+    } else if data.starts_with(b"\x89PNG") {
+        let decoder = PngDecoder::new(cursor).ok()?;
+
+        if !decoder.is_apng().ok()? {
+            return None;
+        }
+
+        decoder.apng().ok()?.into_frames().collect_frames().ok()?
+    } else {
+        return None;
+    };
+
+    let (width, height) = frames.first()?.buffer().dimensions();
+
+    let frames = frames
+        .into_iter()
+        .map(|frame| {
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let delay = Duration::from_secs_f64(numer as f64 / denom.max(1) as f64 / 1000.0);
+
+            // browsers treat very short delays as 100ms, so we do the same
+            let delay = match delay < Duration::from_millis(20) {
+                true => Duration::from_millis(100),
+                false => delay,
+            };
+
+            AnimationFrameData {
+                delay,
+                pixels: Arc::from(frame.into_buffer().into_raw()),
+            }
+        })
+        .collect();
+
+    Some((width, height, frames))
+}
+
+// without the feature, images are always shown as still images
+#[cfg(not(feature = "animated-images"))]
+fn decode_frames(_data: &[u8]) -> Option<(u32, u32, Vec<AnimationFrameData>)> {
+    None
+}
+
+struct AnimationFrameData {
+    delay:  Duration,
+    pixels: Arc<[u8]>,
+}
+
+impl Animation {
+    fn decode(data: &[u8]) -> Option<Self> {
+        let (width, height, frames) = decode_frames(data)?;
+
+        Some(Self {
+            width,
+            height,
+            frames,
+            index: 0,
+            elapsed: Duration::ZERO,
+            loops: 0,
+            sized: true,
+            in_viewport: true,
+            animating: false,
+        })
+    }
+
+    fn current(&self) -> Arc<[u8]> {
+        self.frames[self.index].pixels.clone()
+    }
+
+    fn is_finished(&self, loop_count: Option<u32>) -> bool {
+        loop_count.is_some_and(|loop_count| self.loops >= loop_count)
+    }
+
+    fn restart(&mut self) {
+        self.index = 0;
+        self.elapsed = Duration::ZERO;
+        self.loops = 0;
+    }
+
+    fn advance(&mut self, delta: Duration, loop_count: Option<u32>) -> bool {
+        let previous = self.index;
+        self.elapsed += delta;
+
+        while !self.is_finished(loop_count) && self.elapsed >= self.frames[self.index].delay {
+            self.elapsed -= self.frames[self.index].delay;
+
+            if self.index + 1 < self.frames.len() {
+                self.index += 1;
+            } else {
+                self.loops += 1;
+
+                if !self.is_finished(loop_count) {
+                    self.index = 0;
+                }
+            }
+        }
+
+        self.index != previous
+    }
+
+    fn update<P>(&mut self, cx: &mut Context<P>, playing: bool, loop_count: Option<u32>)
+    where
+        P: HasImage,
+    {
+        let should_animate = playing
+            && self.sized
+            && self.in_viewport
+            && self.frames.len() > 1
+            && !self.is_finished(loop_count);
+
+        if self.animating != should_animate {
+            match should_animate {
+                true => cx.start_animating(),
+                false => cx.stop_animating(),
+            }
+
+            self.animating = should_animate;
+        }
+    }
+
+    fn stop<P>(self, cx: &mut Context<P>)
+    where
+        P: HasImage,
+    {
+        if self.animating {
+            cx.stop_animating();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn animation(delays: &[u64]) -> Animation {
+        let frames = delays
+            .iter()
+            .map(|&delay| AnimationFrameData {
+                delay:  Duration::from_millis(delay),
+                pixels: Arc::from([0u8; 4]),
+            })
+            .collect();
+
+        Animation {
+            width: 1,
+            height: 1,
+            frames,
+            index: 0,
+            elapsed: Duration::ZERO,
+            loops: 0,
+            sized: true,
+            in_viewport: true,
+            animating: false,
+        }
+    }
+
+    #[test]
+    fn advance_waits_for_delay() {
+        let mut animation = animation(&[100, 100]);
+
+        assert!(!animation.advance(Duration::from_millis(50), None));
+        assert_eq!(animation.index, 0);
+
+        assert!(animation.advance(Duration::from_millis(50), None));
+        assert_eq!(animation.index, 1);
+        assert_eq!(animation.elapsed, Duration::ZERO);
+    }
+
+    #[test]
+    fn advance_skips_frames() {
+        let mut animation = animation(&[100, 50, 100]);
+
+        assert!(animation.advance(Duration::from_millis(170), None));
+        assert_eq!(animation.index, 2);
+        assert_eq!(
+            animation.elapsed,
+            Duration::from_millis(20)
+        );
+    }
+
+    #[test]
+    fn advance_loops() {
+        let mut animation = animation(&[100, 100]);
+
+        assert!(animation.advance(Duration::from_millis(350), None));
+        assert_eq!(animation.index, 1);
+        assert_eq!(animation.loops, 1);
+        assert_eq!(
+            animation.elapsed,
+            Duration::from_millis(50)
+        );
+    }
+
+    #[test]
+    fn advance_stops_after_loop_count() {
+        let mut animation = animation(&[100, 100]);
+
+        assert!(animation.advance(Duration::from_millis(1000), Some(2)));
+        assert_eq!(animation.index, 1);
+        assert_eq!(animation.loops, 2);
+        assert!(animation.is_finished(Some(2)));
+
+        assert!(!animation.advance(Duration::from_millis(100), Some(2)));
+        assert_eq!(animation.index, 1);
     }
 }
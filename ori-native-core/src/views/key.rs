@@ -0,0 +1,465 @@
+use std::str::FromStr;
+
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, KeyEvent, Lifecycle, NativeWidget, ParseShortcutError, Pod, PodMut, Shortcut,
+    WidgetView,
+    native::{HasKeyHandler, HasShortcuts, NativeKeyHandler, NativeShortcuts, ShortcutScope},
+};
+
+pub fn on_key<V, T, H>(
+    contents: V,
+    on_key: impl FnMut(&mut T, KeyEvent) -> H + 'static,
+) -> KeyHandler<V, T>
+where
+    H: Into<Handled>,
+{
+    KeyHandler::new(contents, on_key)
+}
+
+pub fn shortcuts<V, T, M, S, A>(
+    contents: V,
+    shortcuts: impl IntoIterator<Item = (S, M)>,
+    on_shortcut: impl FnMut(&mut T, M) -> A + 'static,
+) -> Shortcuts<V, T, M>
+where
+    S: AsRef<str>,
+    A: Into<Action>,
+{
+    let mut view = Shortcuts::new(contents, on_shortcut);
+
+    for (shortcut, action) in shortcuts {
+        view = view.shortcut(shortcut, action);
+    }
+
+    view
+}
+
+// handling a key only stops it from reaching the enclosing key handlers, the platform still
+// handles it as it would otherwise, e.g. text input still receives it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Handled {
+    Yes,
+    No,
+}
+
+impl From<bool> for Handled {
+    fn from(handled: bool) -> Self {
+        match handled {
+            true => Handled::Yes,
+            false => Handled::No,
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub struct KeyHandler<V, T> {
+    contents:  V,
+    disabled:  bool,
+    focusable: bool,
+    on_key:    Box<dyn FnMut(&mut T, KeyEvent) -> Handled>,
+}
+
+impl<V, T> KeyHandler<V, T> {
+    pub fn new<H>(contents: V, mut on_key: impl FnMut(&mut T, KeyEvent) -> H + 'static) -> Self
+    where
+        H: Into<Handled>,
+    {
+        Self {
+            contents,
+            disabled: false,
+            focusable: false,
+            on_key: Box::new(move |data, event| on_key(data, event).into()),
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    // key events only reach the focused widget and its ancestors, so the handler itself has to
+    // be focusable for contents that aren't
+    pub fn focusable(mut self, focusable: bool) -> Self {
+        self.focusable = focusable;
+        self
+    }
+}
+
+enum KeyHandlerMessage {
+    Key(u64, KeyEvent),
+}
+
+impl<V, T> ViewMarker for KeyHandler<V, T> {}
+impl<P, T, V> View<Context<P>, T> for KeyHandler<V, T>
+where
+    P: HasKeyHandler + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P::KeyHandler>;
+    type State = (V::Widget, KeyHandlerState<P, T, V>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::KeyHandler::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_disabled(self.disabled);
+        widget.set_focusable(self.focusable);
+        widget.set_on_key({
+            let proxy = cx.proxy();
+
+            move |serial, event| {
+                proxy.message(Message::new(
+                    KeyHandlerMessage::Key(serial, event),
+                    view_id,
+                ));
+            }
+        });
+
+        let pod = Pod {
+            node: contents.node,
+            widget,
+        };
+
+        let state = KeyHandlerState {
+            view_id,
+            disabled: self.disabled,
+            focusable: self.focusable,
+            on_key: self.on_key,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.disabled != state.disabled {
+            element.widget.set_disabled(self.disabled);
+            state.disabled = self.disabled;
+        }
+
+        if self.focusable != state.focusable {
+            element.widget.set_focusable(self.focusable);
+            state.focusable = self.focusable;
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+
+        state.on_key = self.on_key;
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        if let Some(KeyHandlerMessage::Key(serial, event)) = message.take_targeted(state.view_id) {
+            if state.disabled {
+                return Action::new();
+            }
+
+            // every handler the event propagates through receives it, but only the innermost
+            // one that handles it should act on it
+            if cx.handled_key() == Some(serial) {
+                return Action::new();
+            }
+
+            if let Handled::Yes = (state.on_key)(data, event) {
+                cx.set_handled_key(serial);
+            }
+
+            // the handler may have changed the data either way
+            return ().into();
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod {
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct KeyHandlerState<P, T, V>
+where
+    P: HasKeyHandler,
+    V: WidgetView<P, T>,
+{
+    view_id:   ViewId,
+    disabled:  bool,
+    focusable: bool,
+    on_key:    Box<dyn FnMut(&mut T, KeyEvent) -> Handled>,
+    state:     V::State,
+}
+
+#[allow(clippy::type_complexity)]
+pub struct Shortcuts<V, T, M> {
+    contents:    V,
+    scope:       ShortcutScope,
+    shortcuts:   Vec<(Shortcut, M)>,
+    disabled:    bool,
+    on_shortcut: Box<dyn FnMut(&mut T, M) -> Action>,
+}
+
+impl<V, T, M> Shortcuts<V, T, M> {
+    pub fn new<A>(contents: V, mut on_shortcut: impl FnMut(&mut T, M) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        Self {
+            contents,
+            scope: ShortcutScope::Subtree,
+            shortcuts: Vec::new(),
+            disabled: false,
+            on_shortcut: Box::new(move |data, action| on_shortcut(data, action).into()),
+        }
+    }
+
+    // invalid shortcuts are skipped, use `try_shortcut` to handle them
+    pub fn shortcut(self, shortcut: impl AsRef<str>, action: M) -> Self {
+        let shortcut = shortcut.as_ref();
+
+        match Shortcut::from_str(shortcut) {
+            Ok(shortcut) => self.with_shortcut(shortcut, action),
+            Err(err) => {
+                tracing::warn!("skipping shortcut: {err}");
+                self
+            }
+        }
+    }
+
+    pub fn try_shortcut(
+        self,
+        shortcut: impl AsRef<str>,
+        action: M,
+    ) -> Result<Self, ParseShortcutError> {
+        let shortcut = Shortcut::from_str(shortcut.as_ref())?;
+        Ok(self.with_shortcut(shortcut, action))
+    }
+
+    pub fn with_shortcut(mut self, shortcut: Shortcut, action: M) -> Self {
+        self.shortcuts.push((shortcut, action));
+        self
+    }
+
+    pub fn scope(mut self, scope: ShortcutScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+enum ShortcutsMessage {
+    Triggered(usize),
+}
+
+impl<V, T, M> ViewMarker for Shortcuts<V, T, M> {}
+impl<P, T, V, M> View<Context<P>, T> for Shortcuts<V, T, M>
+where
+    P: HasShortcuts + Proxied,
+    V: WidgetView<P, T>,
+    M: Clone + 'static,
+{
+    type Element = Pod<P::Shortcuts>;
+    type State = (V::Widget, ShortcutsState<P, T, V, M>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::Shortcuts::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_scope(self.scope);
+        widget.set_disabled(self.disabled);
+        set_shortcuts::<P, _>(
+            &mut widget,
+            cx,
+            view_id,
+            &self.shortcuts,
+        );
+
+        let pod = Pod {
+            node: contents.node,
+            widget,
+        };
+
+        let state = ShortcutsState {
+            view_id,
+            scope: self.scope,
+            shortcuts: self.shortcuts,
+            disabled: self.disabled,
+            on_shortcut: self.on_shortcut,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.scope != state.scope {
+            element.widget.set_scope(self.scope);
+            state.scope = self.scope;
+        }
+
+        if self.disabled != state.disabled {
+            element.widget.set_disabled(self.disabled);
+            state.disabled = self.disabled;
+        }
+
+        let changed = self.shortcuts.len() != state.shortcuts.len()
+            || (self.shortcuts.iter())
+                .zip(&state.shortcuts)
+                .any(|((a, _), (b, _))| a != b);
+
+        if changed {
+            set_shortcuts::<P, _>(
+                element.widget,
+                cx,
+                state.view_id,
+                &self.shortcuts,
+            );
+        }
+
+        state.shortcuts = self.shortcuts;
+        state.on_shortcut = self.on_shortcut;
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        if let Some(ShortcutsMessage::Triggered(index)) = message.take_targeted(state.view_id) {
+            return match state.shortcuts.get(index) {
+                Some((_, action)) if !state.disabled => (state.on_shortcut)(data, action.clone()),
+                _ => Action::new(),
+            };
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod {
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+fn set_shortcuts<P, M>(
+    widget: &mut P::Shortcuts,
+    cx: &mut Context<P>,
+    view_id: ViewId,
+    shortcuts: &[(Shortcut, M)],
+) where
+    P: HasShortcuts + Proxied,
+{
+    let proxy = cx.proxy();
+    let shortcuts: Vec<_> = shortcuts
+        .iter()
+        .map(|(shortcut, _)| shortcut.clone())
+        .collect();
+
+    widget.set_shortcuts(&shortcuts, move |index| {
+        proxy.message(Message::new(
+            ShortcutsMessage::Triggered(index),
+            view_id,
+        ));
+    });
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct ShortcutsState<P, T, V, M>
+where
+    P: HasShortcuts,
+    V: WidgetView<P, T>,
+{
+    view_id:     ViewId,
+    scope:       ShortcutScope,
+    shortcuts:   Vec<(Shortcut, M)>,
+    disabled:    bool,
+    on_shortcut: Box<dyn FnMut(&mut T, M) -> Action>,
+    state:       V::State,
+}
@@ -0,0 +1,489 @@
+use std::ops::Range;
+
+use ori::{Action, Message, Mut, View, ViewMarker};
+
+use crate::{
+    Context, Layout, Lifecycle, NativeWidget, Pod, WidgetView,
+    native::{HasGroup, NativeGroup},
+    views::ScrollViewport,
+};
+
+const INITIAL_ROWS: usize = 32;
+
+pub fn list<V, T>(len: usize, build: impl FnMut(&T, usize) -> V + 'static) -> List<V, T> {
+    List::new(len, build)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RowHeight {
+    Fixed(f32),
+    Measured(f32),
+}
+
+#[allow(clippy::type_complexity)]
+pub struct List<V, T> {
+    style:      taffy::Style,
+    len:        usize,
+    build:      Box<dyn FnMut(&T, usize) -> V>,
+    row_height: RowHeight,
+    overscan:   usize,
+}
+
+impl<V, T> List<V, T> {
+    pub fn new(len: usize, build: impl FnMut(&T, usize) -> V + 'static) -> Self {
+        Self {
+            style: Default::default(),
+            len,
+            build: Box::new(build),
+            row_height: RowHeight::Measured(40.0),
+            overscan: 4,
+        }
+    }
+
+    pub fn row_height(mut self, height: f32) -> Self {
+        self.row_height = RowHeight::Fixed(height);
+        self
+    }
+
+    pub fn estimated_row_height(mut self, height: f32) -> Self {
+        self.row_height = RowHeight::Measured(height);
+        self
+    }
+
+    pub fn overscan(mut self, rows: usize) -> Self {
+        self.overscan = rows;
+        self
+    }
+}
+
+impl<V, T> Layout for List<V, T> {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        &mut self.style
+    }
+}
+
+impl<V, T> ViewMarker for List<V, T> {}
+impl<P, T, V> View<Context<P>, T> for List<V, T>
+where
+    P: HasGroup,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P::Group>;
+    type State = ListState<P, T, V>;
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let mut state = ListState {
+            style:      self.style,
+            build:      self.build,
+            row_height: self.row_height,
+            overscan:   self.overscan,
+            heights:    Vec::new(),
+            offsets:    Vec::new(),
+            viewport:   None,
+            scrolled:   cx.is_in_scroll(),
+            rows:       Vec::new(),
+        };
+
+        state.resize(self.len);
+
+        let node = cx.new_layout_node(state.list_style(), &[]);
+        let mut widget = P::Group::build(&mut cx.platform);
+
+        state.update_rows(node, &mut widget, cx, data, false);
+
+        (Pod { node, widget }, state)
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        state.style = self.style;
+        state.build = self.build;
+        state.overscan = self.overscan;
+        state.scrolled = cx.is_in_scroll();
+
+        if self.row_height != state.row_height {
+            state.row_height = self.row_height;
+            state.heights.clear();
+        }
+
+        state.resize(self.len);
+
+        let _ = cx.set_layout_style(*element.node, state.list_style());
+        state.update_rows(
+            *element.node,
+            element.widget,
+            cx,
+            data,
+            true,
+        );
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(viewport) = message.get::<ScrollViewport>()
+            && let Some((_, y)) = viewport.offset_of(cx, *element.node)
+        {
+            let top = viewport.y - y;
+            let visible = Some((top, top + viewport.height));
+
+            if state.viewport != visible {
+                state.viewport = visible;
+                state.update_rows(
+                    *element.node,
+                    element.widget,
+                    cx,
+                    data,
+                    false,
+                );
+            }
+        }
+
+        if let Some(Lifecycle::Layout) = message.get() {
+            state.layout(*element.node, element.widget, cx);
+        }
+
+        let mut action = Action::new();
+
+        for row in &mut state.rows {
+            action |= V::message(
+                row.element.as_mut(row.slot),
+                &mut row.state,
+                cx,
+                data,
+                message,
+            );
+        }
+
+        action
+    }
+
+    fn teardown(element: Self::Element, state: Self::State, cx: &mut Context<P>) {
+        for row in state.rows {
+            V::teardown(row.element, row.state, cx);
+            let _ = cx.remove_layout_node(row.slot);
+        }
+
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+struct Row<P, T, V>
+where
+    P: HasGroup,
+    V: WidgetView<P, T>,
+{
+    index:   usize,
+    slot:    taffy::NodeId,
+    element: Pod<V::Widget>,
+    state:   V::State,
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct ListState<P, T, V>
+where
+    P: HasGroup,
+    V: WidgetView<P, T>,
+{
+    style:      taffy::Style,
+    build:      Box<dyn FnMut(&T, usize) -> V>,
+    row_height: RowHeight,
+    overscan:   usize,
+    heights:    Vec<f32>,
+    offsets:    Vec<f32>,
+    viewport:   Option<(f32, f32)>,
+    scrolled:   bool,
+    rows:       Vec<Row<P, T, V>>,
+}
+
+impl<P, T, V> ListState<P, T, V>
+where
+    P: HasGroup,
+    V: WidgetView<P, T>,
+{
+    fn resize(&mut self, len: usize) {
+        let height = match self.row_height {
+            RowHeight::Fixed(height) => height,
+            RowHeight::Measured(height) => height,
+        };
+
+        self.heights.resize(len, height);
+        self.update_offsets();
+    }
+
+    fn update_offsets(&mut self) {
+        self.offsets.clear();
+        self.offsets.push(0.0);
+
+        let mut offset = 0.0;
+
+        for height in &self.heights {
+            offset += height;
+            self.offsets.push(offset);
+        }
+    }
+
+    fn list_style(&self) -> taffy::Style {
+        let mut style = self.style.clone();
+        style.size.height = taffy::Dimension::length(self.offsets.last().copied().unwrap_or(0.0));
+        style
+    }
+
+    fn slot_style(&self, index: usize) -> taffy::Style {
+        let height = match self.row_height {
+            RowHeight::Fixed(height) => taffy::Dimension::length(height),
+            RowHeight::Measured(_) => taffy::Dimension::auto(),
+        };
+
+        taffy::Style {
+            display: taffy::Display::Flex,
+            flex_direction: taffy::FlexDirection::Column,
+            position: taffy::Position::Absolute,
+            inset: taffy::Rect {
+                left:   taffy::LengthPercentageAuto::length(0.0),
+                right:  taffy::LengthPercentageAuto::length(0.0),
+                top:    taffy::LengthPercentageAuto::length(self.offsets[index]),
+                bottom: taffy::LengthPercentageAuto::auto(),
+            },
+            size: taffy::Size {
+                width: taffy::Dimension::auto(),
+                height,
+            },
+            ..Default::default()
+        }
+    }
+
+    fn visible_range(&self) -> Range<usize> {
+        let len = self.heights.len();
+
+        // inside a scroll only the first rows are built until the viewport is known, outside of
+        // one every row is visible
+        let Some((top, bottom)) = self.viewport else {
+            return match self.scrolled {
+                true => 0..len.min(INITIAL_ROWS),
+                false => 0..len,
+            };
+        };
+
+        visible_rows(
+            &self.offsets,
+            top,
+            bottom,
+            self.overscan,
+        )
+    }
+
+    fn update_rows(
+        &mut self,
+        node: taffy::NodeId,
+        group: &mut P::Group,
+        cx: &mut Context<P>,
+        data: &mut T,
+        rebuild: bool,
+    ) {
+        let range = self.visible_range();
+
+        // rows that scrolled out of view are reused for the rows that scrolled into view, by
+        // rebuilding them with the view of their new index
+        let mut free: Vec<usize> = (self.rows.iter().enumerate())
+            .filter(|(_, row)| !range.contains(&row.index))
+            .map(|(position, _)| position)
+            .collect();
+
+        for (position, row) in self.rows.iter_mut().enumerate() {
+            if rebuild && !free.contains(&position) {
+                let view = (self.build)(data, row.index);
+                view.rebuild(
+                    row.element.as_mut(row.slot),
+                    &mut row.state,
+                    cx,
+                    data,
+                );
+            }
+        }
+
+        let missing: Vec<usize> = range
+            .filter(|index| !self.rows.iter().any(|row| row.index == *index))
+            .collect();
+
+        free.reverse();
+
+        for index in missing {
+            let view = (self.build)(data, index);
+            let style = self.slot_style(index);
+
+            if let Some(position) = free.pop() {
+                let row = &mut self.rows[position];
+                row.index = index;
+
+                let _ = cx.set_layout_style(row.slot, style);
+                view.rebuild(
+                    row.element.as_mut(row.slot),
+                    &mut row.state,
+                    cx,
+                    data,
+                );
+            } else {
+                let (element, state) = view.build(cx, data);
+                let slot = cx.new_layout_node(style, &[element.node]);
+
+                let position = self.rows.len();
+                let _ = cx.insert_layout_child(node, position, slot);
+                group.insert_child(position, element.widget.widget());
+
+                self.rows.push(Row {
+                    index,
+                    slot,
+                    element,
+                    state,
+                });
+            }
+        }
+
+        // whatever wasn't reused is torn down, back to front to keep the positions valid
+        free.sort_unstable();
+
+        for position in free.into_iter().rev() {
+            let row = self.rows.remove(position);
+            group.remove_child(position);
+
+            V::teardown(row.element, row.state, cx);
+            let _ = cx.remove_layout_node(row.slot);
+        }
+
+        for position in 0..self.rows.len() {
+            let style = self.slot_style(self.rows[position].index);
+            let _ = cx.set_layout_style(self.rows[position].slot, style);
+        }
+    }
+
+    fn layout(&mut self, node: taffy::NodeId, group: &mut P::Group, cx: &mut Context<P>) {
+        if let Ok(layout) = cx.get_computed_layout(node) {
+            group.set_size(layout.size.width, layout.size.height);
+        }
+
+        let mut resized = false;
+
+        for (position, row) in self.rows.iter().enumerate() {
+            let Ok(slot) = cx.get_computed_layout(row.slot).cloned() else {
+                continue;
+            };
+
+            if let Ok(layout) = cx.get_computed_layout(row.element.node) {
+                group.set_child_layout(
+                    position,
+                    slot.location.x + layout.location.x,
+                    slot.location.y + layout.location.y,
+                    layout.size.width,
+                    layout.size.height,
+                );
+            }
+
+            if let RowHeight::Measured(_) = self.row_height
+                && (self.heights[row.index] - slot.size.height).abs() > 0.5
+            {
+                self.heights[row.index] = slot.size.height;
+                resized = true;
+            }
+        }
+
+        // measured rows that turned out to differ from the estimate move the rows after them,
+        // which requires another layout pass
+        if resized {
+            self.update_offsets();
+            let _ = cx.set_layout_style(node, self.list_style());
+
+            for row in &self.rows {
+                let _ = cx.set_layout_style(row.slot, self.slot_style(row.index));
+            }
+        }
+    }
+}
+
+// the offsets are those of the start of every row, followed by the end of the last one
+fn visible_rows(offsets: &[f32], top: f32, bottom: f32, overscan: usize) -> Range<usize> {
+    let len = offsets.len() - 1;
+
+    // the first row ending below the top of the viewport, and the first starting below its
+    // bottom
+    let start = offsets[1..].partition_point(|&end| end <= top);
+    let end = offsets[..len].partition_point(|&start| start < bottom);
+
+    let start = start.saturating_sub(overscan);
+    let end = (end + overscan).min(len);
+
+    start..end.max(start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ten rows of ten
+    const OFFSETS: [f32; 11] = [
+        0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 100.0,
+    ];
+
+    #[test]
+    fn visible_rows_in_viewport() {
+        assert_eq!(
+            visible_rows(&OFFSETS, 0.0, 25.0, 0),
+            0..3
+        );
+        assert_eq!(
+            visible_rows(&OFFSETS, 10.0, 30.0, 0),
+            1..3
+        );
+        assert_eq!(
+            visible_rows(&OFFSETS, 15.0, 35.0, 0),
+            1..4
+        );
+    }
+
+    #[test]
+    fn visible_rows_overscan() {
+        assert_eq!(
+            visible_rows(&OFFSETS, 40.0, 60.0, 2),
+            2..8
+        );
+        assert_eq!(
+            visible_rows(&OFFSETS, 0.0, 10.0, 4),
+            0..5
+        );
+        assert_eq!(
+            visible_rows(&OFFSETS, 90.0, 100.0, 4),
+            5..10
+        );
+    }
+
+    #[test]
+    fn visible_rows_outside() {
+        assert_eq!(
+            visible_rows(&OFFSETS, 200.0, 300.0, 0),
+            10..10
+        );
+        assert_eq!(
+            visible_rows(&OFFSETS, -50.0, -10.0, 0),
+            0..0
+        );
+    }
+
+    #[test]
+    fn visible_rows_empty() {
+        assert_eq!(
+            visible_rows(&[0.0], 0.0, 100.0, 4),
+            0..0
+        );
+    }
+}
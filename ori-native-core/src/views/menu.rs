@@ -0,0 +1,292 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Lifecycle, NativeWidget, Pod, PodMut, WidgetView,
+    native::{HasContextMenu, MenuEntry, NativeContextMenu},
+};
+
+pub fn context_menu<V, T>(
+    contents: V,
+    items: impl IntoIterator<Item = MenuItem<T>>,
+) -> ContextMenu<V, T> {
+    ContextMenu::new(contents, items)
+}
+
+pub fn menu_item<T, A>(
+    label: impl Into<String>,
+    mut on_select: impl FnMut(&mut T) -> A + 'static,
+) -> MenuItem<T>
+where
+    A: Into<Action>,
+{
+    MenuItem::Item {
+        label:     label.into(),
+        checked:   None,
+        enabled:   true,
+        on_select: Box::new(move |data| on_select(data).into()),
+    }
+}
+
+pub fn separator<T>() -> MenuItem<T> {
+    MenuItem::Separator
+}
+
+pub fn submenu<T>(
+    label: impl Into<String>,
+    items: impl IntoIterator<Item = MenuItem<T>>,
+) -> MenuItem<T> {
+    MenuItem::Submenu {
+        label: label.into(),
+        items: items.into_iter().collect(),
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub enum MenuItem<T> {
+    Item {
+        label:     String,
+        checked:   Option<bool>,
+        enabled:   bool,
+        on_select: Box<dyn FnMut(&mut T) -> Action>,
+    },
+    Separator,
+    Submenu {
+        label: String,
+        items: Vec<MenuItem<T>>,
+    },
+}
+
+impl<T> MenuItem<T> {
+    pub fn checked(mut self, checked: bool) -> Self {
+        if let MenuItem::Item {
+            checked: ref mut c, ..
+        } = self
+        {
+            *c = Some(checked);
+        }
+
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        if let MenuItem::Item {
+            enabled: ref mut e, ..
+        } = self
+        {
+            *e = enabled;
+        }
+
+        self
+    }
+}
+
+// splits the items into what the platform needs to show them, and the callbacks in the order
+// the platform numbers them
+#[allow(clippy::type_complexity)]
+fn flatten<T>(
+    items: Vec<MenuItem<T>>,
+    callbacks: &mut Vec<Box<dyn FnMut(&mut T) -> Action>>,
+) -> Vec<MenuEntry> {
+    (items.into_iter())
+        .map(|item| match item {
+            MenuItem::Item {
+                label,
+                checked,
+                enabled,
+                on_select,
+            } => {
+                callbacks.push(on_select);
+
+                MenuEntry::Item {
+                    label,
+                    checked,
+                    enabled,
+                }
+            }
+
+            MenuItem::Separator => MenuEntry::Separator,
+
+            MenuItem::Submenu { label, items } => MenuEntry::Submenu {
+                label,
+                entries: flatten(items, callbacks),
+            },
+        })
+        .collect()
+}
+
+#[allow(clippy::type_complexity)]
+pub struct ContextMenu<V, T> {
+    contents:  V,
+    entries:   Vec<MenuEntry>,
+    callbacks: Vec<Box<dyn FnMut(&mut T) -> Action>>,
+    disabled:  bool,
+}
+
+impl<V, T> ContextMenu<V, T> {
+    pub fn new(contents: V, items: impl IntoIterator<Item = MenuItem<T>>) -> Self {
+        let mut callbacks = Vec::new();
+        let entries = flatten(
+            items.into_iter().collect(),
+            &mut callbacks,
+        );
+
+        Self {
+            contents,
+            entries,
+            callbacks,
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+enum ContextMenuMessage {
+    Selected(usize),
+}
+
+impl<V, T> ViewMarker for ContextMenu<V, T> {}
+impl<P, T, V> View<Context<P>, T> for ContextMenu<V, T>
+where
+    P: HasContextMenu + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P::ContextMenu>;
+    type State = (V::Widget, ContextMenuState<P, T, V>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::ContextMenu::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        set_menu::<P>(&mut widget, cx, view_id, &self.entries);
+        widget.set_disabled(self.disabled);
+
+        let pod = Pod {
+            node: contents.node,
+            widget,
+        };
+
+        let state = ContextMenuState {
+            view_id,
+            entries: self.entries,
+            callbacks: self.callbacks,
+            disabled: self.disabled,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.entries != state.entries {
+            set_menu::<P>(
+                element.widget,
+                cx,
+                state.view_id,
+                &self.entries,
+            );
+            state.entries = self.entries;
+        }
+
+        if self.disabled != state.disabled {
+            element.widget.set_disabled(self.disabled);
+            state.disabled = self.disabled;
+        }
+
+        state.callbacks = self.callbacks;
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        if let Some(ContextMenuMessage::Selected(index)) = message.take_targeted(state.view_id) {
+            return match state.callbacks.get_mut(index) {
+                Some(on_select) if !state.disabled => on_select(data),
+                _ => Action::new(),
+            };
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod {
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+fn set_menu<P>(
+    widget: &mut P::ContextMenu,
+    cx: &mut Context<P>,
+    view_id: ViewId,
+    entries: &[MenuEntry],
+) where
+    P: HasContextMenu + Proxied,
+{
+    let proxy = cx.proxy();
+
+    widget.set_menu(entries, move |index| {
+        proxy.message(Message::new(
+            ContextMenuMessage::Selected(index),
+            view_id,
+        ));
+    });
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct ContextMenuState<P, T, V>
+where
+    P: HasContextMenu,
+    V: WidgetView<P, T>,
+{
+    view_id:   ViewId,
+    entries:   Vec<MenuEntry>,
+    callbacks: Vec<Box<dyn FnMut(&mut T) -> Action>>,
+    disabled:  bool,
+    state:     V::State,
+}
@@ -1,20 +1,52 @@
 mod animate;
+mod canvas;
+mod clipboard;
+mod dialog;
+mod dragdrop;
+mod draggable;
+mod file_dialog;
 mod flex;
+mod focus;
+mod gesture;
+mod icon;
 mod image;
+mod key;
+mod list;
+mod menu;
+mod pointer;
+mod popover;
 mod pressable;
 mod scroll;
+mod sticky;
 mod text;
 mod textinput;
+mod tooltip;
 mod transition;
 mod window;
 
 pub use animate::{Animate, AnimationFrame, animate};
+pub use canvas::{Canvas, canvas};
+pub use clipboard::{Clipboard, ClipboardRead, clipboard, read_clipboard, write_clipboard};
+pub use dialog::{Alert, Dialog, alert, dialog};
+pub use dragdrop::{DragSource, DropTarget, drag_source, drop_target};
+pub use draggable::{Drag, DragAxis, Draggable, draggable};
+pub use file_dialog::{FileDialog, open_file, open_files, save_file, select_folder};
 pub use flex::{Flex, column, row};
-pub use image::{Image, image};
+pub use focus::{FocusTarget, WatchFocus, focus_target, request_focus, watch_focus};
+pub use gesture::{Gesture, Swipe, SwipeDirection, gesture};
+pub use icon::{Icon, icon};
+pub use image::{Image, image, image_rgba};
+pub use key::{Handled, KeyHandler, Shortcuts, on_key, shortcuts};
+pub use list::{List, list};
+pub use menu::{ContextMenu, MenuItem, context_menu, menu_item, separator, submenu};
+pub use pointer::{Pointer, pointer};
+pub use popover::{Popover, popover};
 pub use pressable::{PressState, Pressable, pressable};
-pub use scroll::{Scroll, hscroll, vscroll};
+pub use scroll::{NoIndicator, Scroll, ScrollViewport, hscroll, scroll, vscroll};
+pub use sticky::{Sticky, sticky};
 pub use text::{Text, text};
 pub use textinput::{Newline, Submit, TextInput, textinput};
+pub use tooltip::{Tooltip, TooltipText, tooltip, tooltip_text};
 pub use transition::{
     Back, BackIn, BackInOut, Ease, Elastic, ElasticIn, Lerp, Linear, Transition, transition,
 };
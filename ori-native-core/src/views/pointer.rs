@@ -0,0 +1,246 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, CursorIcon, Lifecycle, NativeWidget, Pod, PodMut, Point, WidgetView,
+    native::{HasPointer, NativePointer, PointerEvent},
+};
+
+pub fn pointer<V, T>(contents: V) -> Pointer<V, T> {
+    Pointer::new(contents)
+}
+
+#[allow(clippy::type_complexity)]
+pub struct Pointer<V, T> {
+    contents: V,
+    cursor:   Option<CursorIcon>,
+    disabled: bool,
+    on_enter: Box<dyn FnMut(&mut T, Point<f32>) -> Action>,
+    on_move:  Box<dyn FnMut(&mut T, Point<f32>) -> Action>,
+    on_leave: Box<dyn FnMut(&mut T) -> Action>,
+    on_hover: Box<dyn FnMut(&mut T, bool) -> Action>,
+}
+
+impl<V, T> Pointer<V, T> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            contents,
+            cursor: None,
+            disabled: false,
+            on_enter: Box::new(|_, _| Action::new()),
+            on_move: Box::new(|_, _| Action::new()),
+            on_leave: Box::new(|_| Action::new()),
+            on_hover: Box::new(|_, _| Action::new()),
+        }
+    }
+
+    pub fn cursor(mut self, cursor: CursorIcon) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn on_enter<A>(
+        mut self,
+        mut on_enter: impl FnMut(&mut T, Point<f32>) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_enter = Box::new(move |data, position| on_enter(data, position).into());
+        self
+    }
+
+    pub fn on_move<A>(mut self, mut on_move: impl FnMut(&mut T, Point<f32>) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_move = Box::new(move |data, position| on_move(data, position).into());
+        self
+    }
+
+    pub fn on_leave<A>(mut self, mut on_leave: impl FnMut(&mut T) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_leave = Box::new(move |data| on_leave(data).into());
+        self
+    }
+
+    pub fn on_hover<A>(mut self, mut on_hover: impl FnMut(&mut T, bool) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_hover = Box::new(move |data, hovered| on_hover(data, hovered).into());
+        self
+    }
+}
+
+enum PointerMessage {
+    Pointer(PointerEvent),
+}
+
+impl<V, T> ViewMarker for Pointer<V, T> {}
+impl<P, T, V> View<Context<P>, T> for Pointer<V, T>
+where
+    P: HasPointer + Proxied,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P::Pointer>;
+    type State = (V::Widget, PointerState<P, T, V>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::Pointer::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_cursor(self.cursor);
+        widget.set_disabled(self.disabled);
+        widget.set_on_pointer({
+            let proxy = cx.proxy();
+
+            move |event| {
+                proxy.message(Message::new(
+                    PointerMessage::Pointer(event),
+                    view_id,
+                ));
+            }
+        });
+
+        let pod = Pod {
+            node: contents.node,
+            widget,
+        };
+
+        let state = PointerState {
+            view_id,
+            cursor: self.cursor,
+            disabled: self.disabled,
+            hovered: false,
+            on_enter: self.on_enter,
+            on_move: self.on_move,
+            on_leave: self.on_leave,
+            on_hover: self.on_hover,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.cursor != state.cursor {
+            element.widget.set_cursor(self.cursor);
+            state.cursor = self.cursor;
+        }
+
+        // the pointer is only tracked while enabled, hovering starts over once enabled again
+        if self.disabled != state.disabled {
+            element.widget.set_disabled(self.disabled);
+            state.disabled = self.disabled;
+            state.hovered = false;
+        }
+
+        state.on_enter = self.on_enter;
+        state.on_move = self.on_move;
+        state.on_leave = self.on_leave;
+        state.on_hover = self.on_hover;
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        if let Some(PointerMessage::Pointer(event)) = message.take_targeted(state.view_id) {
+            return match event {
+                _ if state.disabled => Action::new(),
+
+                PointerEvent::Entered(position) => {
+                    state.hovered = true;
+
+                    let mut action = (state.on_hover)(data, true);
+                    action |= (state.on_enter)(data, position);
+                    action
+                }
+
+                PointerEvent::Moved(position) => (state.on_move)(data, position),
+
+                PointerEvent::Left if state.hovered => {
+                    state.hovered = false;
+
+                    let mut action = (state.on_hover)(data, false);
+                    action |= (state.on_leave)(data);
+                    action
+                }
+
+                PointerEvent::Left => Action::new(),
+            };
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod {
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct PointerState<P, T, V>
+where
+    P: HasPointer,
+    V: WidgetView<P, T>,
+{
+    view_id:  ViewId,
+    cursor:   Option<CursorIcon>,
+    disabled: bool,
+    hovered:  bool,
+    on_enter: Box<dyn FnMut(&mut T, Point<f32>) -> Action>,
+    on_move:  Box<dyn FnMut(&mut T, Point<f32>) -> Action>,
+    on_leave: Box<dyn FnMut(&mut T) -> Action>,
+    on_hover: Box<dyn FnMut(&mut T, bool) -> Action>,
+    state:    V::State,
+}
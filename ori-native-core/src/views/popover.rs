@@ -0,0 +1,226 @@
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
+
+use crate::{
+    Context, Lifecycle, NativeWidget, NodeId, Pod, PodMut, WidgetView,
+    native::{HasPopover, NativePopover, PopoverPosition},
+};
+
+pub fn popover<A, C, T>(anchor: A, content: C, open: bool) -> Popover<A, C, T> {
+    Popover::new(anchor, content, open)
+}
+
+#[allow(clippy::type_complexity)]
+pub struct Popover<A, C, T> {
+    anchor:   A,
+    content:  C,
+    open:     bool,
+    position: PopoverPosition,
+    on_close: Box<dyn FnMut(&mut T) -> Action>,
+}
+
+impl<A, C, T> Popover<A, C, T> {
+    pub fn new(anchor: A, content: C, open: bool) -> Self {
+        Self {
+            anchor,
+            content,
+            open,
+            position: PopoverPosition::Bottom,
+            on_close: Box::new(|_| Action::new()),
+        }
+    }
+
+    pub fn position(mut self, position: PopoverPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn on_close<R>(mut self, mut on_close: impl FnMut(&mut T) -> R + 'static) -> Self
+    where
+        R: Into<Action>,
+    {
+        self.on_close = Box::new(move |data| on_close(data).into());
+        self
+    }
+}
+
+enum PopoverMessage {
+    Closed,
+}
+
+impl<A, C, T> ViewMarker for Popover<A, C, T> {}
+impl<P, T, A, C> View<Context<P>, T> for Popover<A, C, T>
+where
+    P: HasPopover + Proxied,
+    A: WidgetView<P, T>,
+    C: WidgetView<P, T>,
+{
+    type Element = Pod<P::Popover>;
+    type State = (A::Widget, PopoverState<P, T, A, C>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (anchor, state) = self.anchor.build(cx, data);
+        let (content, content_state) = self.content.build(cx, data);
+
+        // the popup floats outside the anchor's bounds, so it isn't a child of its layout
+        let node = cx.new_layout_node(Default::default(), &[content.node]);
+
+        let mut widget = P::Popover::build(
+            &mut cx.platform,
+            anchor.widget.widget(),
+            content.widget.widget(),
+        );
+
+        let view_id = ViewId::next();
+
+        widget.set_position(self.position);
+        widget.set_open(self.open);
+        widget.set_on_close({
+            let proxy = cx.proxy();
+
+            move || {
+                proxy.message(Message::new(
+                    PopoverMessage::Closed,
+                    view_id,
+                ));
+            }
+        });
+
+        let pod = Pod {
+            node: anchor.node,
+            widget,
+        };
+
+        let state = PopoverState {
+            view_id,
+            open: self.open,
+            position: self.position,
+            on_close: self.on_close,
+            node,
+            content,
+            content_state,
+            state,
+        };
+
+        (pod, (anchor.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (anchor, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.position != state.position {
+            element.widget.set_position(self.position);
+            state.position = self.position;
+        }
+
+        if self.open != state.open {
+            element.widget.set_open(self.open);
+            state.open = self.open;
+            cx.relayout();
+        }
+
+        state.on_close = self.on_close;
+
+        (self.content).rebuild(
+            state.content.as_mut(state.node),
+            &mut state.content_state,
+            cx,
+            data,
+        );
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: anchor,
+        };
+
+        self.anchor.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (anchor, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get() {
+            if let Ok(layout) = cx.get_computed_layout(*element.node) {
+                (element.widget).set_size(layout.size.width, layout.size.height);
+            }
+
+            if state.open {
+                let _ = cx.compute_layout(state.node, taffy::Size::max_content());
+            }
+
+            if state.open
+                && let Ok(layout) = cx.get_computed_layout(state.content.node)
+            {
+                (element.widget).set_content_size(layout.size.width, layout.size.height);
+            }
+        }
+
+        if let Some(PopoverMessage::Closed) = message.take_targeted(state.view_id) {
+            // closing it from the view also ends up here
+            if !state.open {
+                return Action::new();
+            }
+
+            // the platform closed it, so opening it again has to reach the platform
+            state.open = false;
+            return (state.on_close)(data);
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: anchor,
+        };
+
+        let mut action = A::message(pod, &mut state.state, cx, data, message);
+
+        action |= C::message(
+            state.content.as_mut(state.node),
+            &mut state.content_state,
+            cx,
+            data,
+            message,
+        );
+
+        action
+    }
+
+    fn teardown(element: Self::Element, (anchor, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod {
+            node:   element.node,
+            widget: anchor,
+        };
+
+        A::teardown(pod, state.state, cx);
+        C::teardown(state.content, state.content_state, cx);
+
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(state.node);
+    }
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct PopoverState<P, T, A, C>
+where
+    P: HasPopover,
+    A: WidgetView<P, T>,
+    C: WidgetView<P, T>,
+{
+    view_id:       ViewId,
+    open:          bool,
+    position:      PopoverPosition,
+    on_close:      Box<dyn FnMut(&mut T) -> Action>,
+    node:          NodeId,
+    content:       Pod<C::Widget>,
+    content_state: C::State,
+    state:         A::State,
+}
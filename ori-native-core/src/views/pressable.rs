@@ -1,8 +1,8 @@
 use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
 
 use crate::{
-    Context, Lifecycle, NativeWidget, Pod, PodMut, WidgetView,
-    native::{HasPressable, NativePressable, Press},
+    Context, Lifecycle, NativeWidget, Pod, PodMut, PointerButton, WidgetView,
+    native::{HasPressable, NativePressable, Press, PressEvent},
 };
 
 pub fn pressable<V, T>(build: impl FnMut(&T, PressState) -> V + 'static) -> Pressable<V, T> {
@@ -11,29 +11,73 @@ pub fn pressable<V, T>(build: impl FnMut(&T, PressState) -> V + 'static) -> Pres
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct PressState {
-    pub pressed: bool,
-    pub hovered: bool,
-    pub focused: bool,
+    pub pressed:  bool,
+    pub hovered:  bool,
+    pub focused:  bool,
+    pub disabled: bool,
 }
 
 #[allow(clippy::type_complexity)]
 pub struct Pressable<V, T> {
-    build:    Box<dyn FnMut(&T, PressState) -> V>,
-    on_press: Box<dyn FnMut(&mut T) -> Action>,
-    on_hover: Box<dyn FnMut(&mut T, bool) -> Action>,
-    on_focus: Box<dyn FnMut(&mut T, bool) -> Action>,
+    build:              Box<dyn FnMut(&T, PressState) -> V>,
+    on_press:           Box<dyn FnMut(&mut T) -> Action>,
+    on_double_press:    Box<dyn FnMut(&mut T) -> Action>,
+    on_secondary_press: Box<dyn FnMut(&mut T) -> Action>,
+    on_long_press:      Box<dyn FnMut(&mut T) -> Action>,
+    on_press_event:     Box<dyn FnMut(&mut T, PressEvent) -> Action>,
+    on_hover:           Box<dyn FnMut(&mut T, bool) -> Action>,
+    on_focus:           Box<dyn FnMut(&mut T, bool) -> Action>,
+    focus:              FocusOptions,
+    disabled:           bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct FocusOptions {
+    focusable:  bool,
+    tab_index:  i32,
+    focus_ring: bool,
 }
 
 impl<V, T> Pressable<V, T> {
     pub fn new(build: impl FnMut(&T, PressState) -> V + 'static) -> Self {
         Self {
-            build:    Box::new(build),
-            on_press: Box::new(|_| Action::new()),
-            on_hover: Box::new(|_, _| Action::new()),
-            on_focus: Box::new(|_, _| Action::new()),
+            build:              Box::new(build),
+            on_press:           Box::new(|_| Action::new()),
+            on_double_press:    Box::new(|_| Action::new()),
+            on_secondary_press: Box::new(|_| Action::new()),
+            on_long_press:      Box::new(|_| Action::new()),
+            on_press_event:     Box::new(|_, _| Action::new()),
+            on_hover:           Box::new(|_, _| Action::new()),
+            on_focus:           Box::new(|_, _| Action::new()),
+            focus:              FocusOptions {
+                focusable:  true,
+                tab_index:  0,
+                focus_ring: false,
+            },
+            disabled:           false,
         }
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn focusable(mut self, focusable: bool) -> Self {
+        self.focus.focusable = focusable;
+        self
+    }
+
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.focus.tab_index = tab_index;
+        self
+    }
+
+    pub fn focus_ring(mut self, focus_ring: bool) -> Self {
+        self.focus.focus_ring = focus_ring;
+        self
+    }
+
     pub fn on_press<A>(mut self, mut on_press: impl FnMut(&mut T) -> A + 'static) -> Self
     where
         A: Into<Action>,
@@ -42,6 +86,47 @@ impl<V, T> Pressable<V, T> {
         self
     }
 
+    pub fn on_double_press<A>(
+        mut self,
+        mut on_double_press: impl FnMut(&mut T) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_double_press = Box::new(move |data| on_double_press(data).into());
+        self
+    }
+
+    pub fn on_secondary_press<A>(
+        mut self,
+        mut on_secondary_press: impl FnMut(&mut T) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_secondary_press = Box::new(move |data| on_secondary_press(data).into());
+        self
+    }
+
+    pub fn on_long_press<A>(mut self, mut on_long_press: impl FnMut(&mut T) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_long_press = Box::new(move |data| on_long_press(data).into());
+        self
+    }
+
+    pub fn on_press_event<A>(
+        mut self,
+        mut on_press_event: impl FnMut(&mut T, PressEvent) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_press_event = Box::new(move |data, event| on_press_event(data, event).into());
+        self
+    }
+
     pub fn on_hover<A>(mut self, mut on_hover: impl FnMut(&mut T, bool) -> A + 'static) -> Self
     where
         A: Into<Action>,
@@ -60,7 +145,7 @@ impl<V, T> Pressable<V, T> {
 }
 
 enum PressableMessage {
-    Pressed(Press),
+    Pressed(PressEvent),
     Hovered(bool),
     Focused(bool),
 }
@@ -76,9 +161,10 @@ where
 
     fn build(mut self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
         let press = PressState {
-            pressed: false,
-            hovered: false,
-            focused: false,
+            pressed:  false,
+            hovered:  false,
+            focused:  false,
+            disabled: self.disabled,
         };
 
         let view = (self.build)(data, press);
@@ -94,9 +180,9 @@ where
         widget.set_on_press({
             let proxy = cx.proxy();
 
-            move |pressed| {
+            move |event| {
                 proxy.message(Message::new(
-                    PressableMessage::Pressed(pressed),
+                    PressableMessage::Pressed(event),
                     view_id,
                 ));
             }
@@ -124,6 +210,9 @@ where
             }
         });
 
+        set_focus::<P>(&mut widget, self.focus);
+        widget.set_disabled(self.disabled);
+
         let pod = Pod {
             node: contents.node,
             widget,
@@ -132,8 +221,14 @@ where
         let state = PressableState {
             press,
             view_id,
+            long_pressed: false,
+            focus: self.focus,
             build: self.build,
             on_press: self.on_press,
+            on_double_press: self.on_double_press,
+            on_secondary_press: self.on_secondary_press,
+            on_long_press: self.on_long_press,
+            on_press_event: self.on_press_event,
             on_hover: self.on_hover,
             on_focus: self.on_focus,
             state,
@@ -149,6 +244,13 @@ where
         cx: &mut Context<P>,
         data: &mut T,
     ) {
+        // a pressable that gets disabled while pressed never sees the release
+        if self.disabled != state.press.disabled {
+            element.widget.set_disabled(self.disabled);
+            state.press.disabled = self.disabled;
+            state.press.pressed &= !self.disabled;
+        }
+
         let view = (self.build)(data, state.press);
         let pod = PodMut {
             parent: element.parent,
@@ -159,6 +261,17 @@ where
         view.rebuild(pod, &mut state.state, cx, data);
         state.build = self.build;
         state.on_press = self.on_press;
+        state.on_double_press = self.on_double_press;
+        state.on_secondary_press = self.on_secondary_press;
+        state.on_long_press = self.on_long_press;
+        state.on_press_event = self.on_press_event;
+        state.on_hover = self.on_hover;
+        state.on_focus = self.on_focus;
+
+        if self.focus != state.focus {
+            set_focus::<P>(element.widget, self.focus);
+            state.focus = self.focus;
+        }
     }
 
     fn message(
@@ -184,22 +297,29 @@ where
             let mut action = Action::new();
 
             match message {
-                PressableMessage::Pressed(pressed) => {
-                    state.press.pressed = matches!(pressed, Press::Pressed);
+                // disabled pressables still track hover and focus for styling, but don't
+                // report any events
+                PressableMessage::Pressed(_) if state.press.disabled => {}
 
-                    if let Press::Released = pressed {
-                        action |= (state.on_press)(data);
-                    }
+                PressableMessage::Pressed(event) => {
+                    action |= (state.on_press_event)(data, event);
+                    action |= pressed(state, data, event);
                 }
 
                 PressableMessage::Hovered(hovered) => {
                     state.press.hovered = hovered;
-                    action |= (state.on_hover)(data, hovered);
+
+                    if !state.press.disabled {
+                        action |= (state.on_hover)(data, hovered);
+                    }
                 }
 
                 PressableMessage::Focused(focused) => {
                     state.press.focused = focused;
-                    action |= (state.on_focus)(data, focused);
+
+                    if !state.press.disabled {
+                        action |= (state.on_focus)(data, focused);
+                    }
                 }
             }
 
@@ -223,6 +343,56 @@ where
     }
 }
 
+fn set_focus<P>(widget: &mut P::Pressable, focus: FocusOptions)
+where
+    P: HasPressable,
+{
+    widget.set_focusable(focus.focusable);
+    widget.set_tab_index(focus.tab_index);
+    widget.set_focus_ring(focus.focus_ring);
+}
+
+fn pressed<P, T, V>(state: &mut PressableState<P, T, V>, data: &mut T, event: PressEvent) -> Action
+where
+    P: HasPressable,
+    V: WidgetView<P, T>,
+{
+    let primary = event.button == PointerButton::Primary;
+
+    if primary {
+        state.press.pressed = matches!(event.press, Press::Pressed);
+    }
+
+    match event.press {
+        Press::Pressed => {
+            state.long_pressed = false;
+            Action::new()
+        }
+
+        // a long press replaces the press that would otherwise follow when released
+        Press::LongPressed => {
+            state.long_pressed = true;
+            (state.on_long_press)(data)
+        }
+
+        Press::Released if primary && !state.long_pressed => {
+            let mut action = (state.on_press)(data);
+
+            if event.clicks == 2 {
+                action |= (state.on_double_press)(data);
+            }
+
+            action
+        }
+
+        Press::Released if event.button == PointerButton::Secondary => {
+            (state.on_secondary_press)(data)
+        }
+
+        Press::Released | Press::Cancelled => Action::new(),
+    }
+}
+
 #[doc(hidden)]
 #[allow(clippy::type_complexity)]
 pub struct PressableState<P, T, V>
@@ -230,11 +400,17 @@ where
     P: HasPressable,
     V: WidgetView<P, T>,
 {
-    press:    PressState,
-    view_id:  ViewId,
-    build:    Box<dyn FnMut(&T, PressState) -> V>,
-    on_press: Box<dyn FnMut(&mut T) -> Action>,
-    on_hover: Box<dyn FnMut(&mut T, bool) -> Action>,
-    on_focus: Box<dyn FnMut(&mut T, bool) -> Action>,
-    state:    V::State,
+    press:              PressState,
+    view_id:            ViewId,
+    long_pressed:       bool,
+    focus:              FocusOptions,
+    build:              Box<dyn FnMut(&T, PressState) -> V>,
+    on_press:           Box<dyn FnMut(&mut T) -> Action>,
+    on_double_press:    Box<dyn FnMut(&mut T) -> Action>,
+    on_secondary_press: Box<dyn FnMut(&mut T) -> Action>,
+    on_long_press:      Box<dyn FnMut(&mut T) -> Action>,
+    on_press_event:     Box<dyn FnMut(&mut T, PressEvent) -> Action>,
+    on_hover:           Box<dyn FnMut(&mut T, bool) -> Action>,
+    on_focus:           Box<dyn FnMut(&mut T, bool) -> Action>,
+    state:              V::State,
 }
@@ -1,39 +1,90 @@
-use ori::{Action, Message, Mut, View, ViewMarker};
+use ori::{Action, Message, Mut, Proxied, Proxy, View, ViewId, ViewMarker};
 
 use crate::{
-    Context, Direction, Layout, Lifecycle, NativeWidget, Pod, WidgetView,
-    native::{HasScroll, NativeScroll},
+    Context, Direction, Layout, Lifecycle, NativeWidget, Platform, Pod, Size, WidgetView,
+    native::{HasScroll, NativeScroll, ScrollOffset, ScrollbarPolicy},
 };
 
-pub fn hscroll<V>(contents: V) -> Scroll<V> {
+pub fn hscroll<V, T>(contents: V) -> Scroll<V, T> {
     Scroll::new(contents, Direction::Horizontal)
 }
 
-pub fn vscroll<V>(contents: V) -> Scroll<V> {
+pub fn vscroll<V, T>(contents: V) -> Scroll<V, T> {
     Scroll::new(contents, Direction::Vertical)
 }
 
-pub struct Scroll<V> {
-    contents:  V,
-    style:     taffy::Style,
-    direction: Direction,
+pub fn scroll<V, T>(contents: V) -> Scroll<V, T> {
+    Scroll::both(contents)
 }
 
-impl<V> Scroll<V> {
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ScrollTarget {
+    Offset(f32),
+    Point(f32, f32),
+    Child(usize),
+}
+
+#[allow(clippy::type_complexity)]
+pub struct Scroll<V, T, I = NoIndicator> {
+    contents:         V,
+    style:            taffy::Style,
+    axes:             ScrollAxes,
+    target:           Option<ScrollTarget>,
+    animated:         bool,
+    stick_to_end:     bool,
+    on_scroll:        Box<dyn FnMut(&mut T, ScrollOffset) -> Action>,
+    on_start_reached: (f32, Box<dyn FnMut(&mut T) -> Action>),
+    on_end_reached:   (f32, Box<dyn FnMut(&mut T) -> Action>),
+    on_refresh:       Option<Box<dyn FnMut(&mut T) -> Action>>,
+    refreshing:       bool,
+    indicator:        Option<I>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ScrollAxes {
+    horizontal: bool,
+    vertical:   bool,
+    hscrollbar: ScrollbarPolicy,
+    vscrollbar: ScrollbarPolicy,
+    kinetic:    bool,
+}
+
+impl<V, T> Scroll<V, T> {
     pub fn new(contents: V, direction: Direction) -> Self {
         let flex_direction = match direction {
             Direction::Horizontal => taffy::FlexDirection::Row,
             Direction::Vertical => taffy::FlexDirection::Column,
         };
 
-        let overflow_x = match direction {
-            Direction::Horizontal => taffy::Overflow::Scroll,
-            Direction::Vertical => taffy::Overflow::Hidden,
-        };
+        let horizontal = matches!(direction, Direction::Horizontal);
+        let vertical = matches!(direction, Direction::Vertical);
+
+        Self::with_axes(
+            contents,
+            flex_direction,
+            horizontal,
+            vertical,
+        )
+    }
+
+    pub fn both(contents: V) -> Self {
+        Self::with_axes(
+            contents,
+            taffy::FlexDirection::Column,
+            true,
+            true,
+        )
+    }
 
-        let overflow_y = match direction {
-            Direction::Horizontal => taffy::Overflow::Hidden,
-            Direction::Vertical => taffy::Overflow::Scroll,
+    fn with_axes(
+        contents: V,
+        flex_direction: taffy::FlexDirection,
+        horizontal: bool,
+        vertical: bool,
+    ) -> Self {
+        let overflow = |scrollable| match scrollable {
+            true => taffy::Overflow::Scroll,
+            false => taffy::Overflow::Hidden,
         };
 
         Self {
@@ -41,34 +92,211 @@ impl<V> Scroll<V> {
             style: taffy::Style {
                 display: taffy::Display::Flex,
                 overflow: taffy::Point {
-                    x: overflow_x,
-                    y: overflow_y,
+                    x: overflow(horizontal),
+                    y: overflow(vertical),
                 },
                 flex_direction,
                 ..Default::default()
             },
-            direction,
+            axes: ScrollAxes {
+                horizontal,
+                vertical,
+                hscrollbar: ScrollbarPolicy::Overlay,
+                vscrollbar: ScrollbarPolicy::Overlay,
+                kinetic: true,
+            },
+            target: None,
+            animated: false,
+            stick_to_end: false,
+            on_scroll: Box::new(|_, _| Action::new()),
+            on_start_reached: (0.0, Box::new(|_| Action::new())),
+            on_end_reached: (0.0, Box::new(|_| Action::new())),
+            on_refresh: None,
+            refreshing: false,
+            indicator: None,
+        }
+    }
+}
+
+impl<V, T, I> Scroll<V, T, I> {
+    pub fn hscrollbar(mut self, policy: ScrollbarPolicy) -> Self {
+        self.axes.hscrollbar = policy;
+        self
+    }
+
+    pub fn vscrollbar(mut self, policy: ScrollbarPolicy) -> Self {
+        self.axes.vscrollbar = policy;
+        self
+    }
+
+    pub fn scrollbars(mut self, policy: ScrollbarPolicy) -> Self {
+        self.axes.hscrollbar = policy;
+        self.axes.vscrollbar = policy;
+        self
+    }
+
+    pub fn kinetic(mut self, kinetic: bool) -> Self {
+        self.axes.kinetic = kinetic;
+        self
+    }
+
+    pub fn scroll_to(mut self, offset: f32) -> Self {
+        self.target = Some(ScrollTarget::Offset(offset));
+        self
+    }
+
+    pub fn scroll_to_point(mut self, x: f32, y: f32) -> Self {
+        self.target = Some(ScrollTarget::Point(x, y));
+        self
+    }
+
+    pub fn scroll_to_child(mut self, index: usize) -> Self {
+        self.target = Some(ScrollTarget::Child(index));
+        self
+    }
+
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    pub fn stick_to_end(mut self, stick_to_end: bool) -> Self {
+        self.stick_to_end = stick_to_end;
+        self
+    }
+
+    pub fn on_scroll<A>(
+        mut self,
+        mut on_scroll: impl FnMut(&mut T, ScrollOffset) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_scroll = Box::new(move |data, offset| on_scroll(data, offset).into());
+        self
+    }
+
+    pub fn on_start_reached<A>(
+        mut self,
+        threshold: f32,
+        mut on_start_reached: impl FnMut(&mut T) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_start_reached = (
+            threshold,
+            Box::new(move |data| on_start_reached(data).into()),
+        );
+        self
+    }
+
+    pub fn on_end_reached<A>(
+        mut self,
+        threshold: f32,
+        mut on_end_reached: impl FnMut(&mut T) -> A + 'static,
+    ) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_end_reached = (
+            threshold,
+            Box::new(move |data| on_end_reached(data).into()),
+        );
+        self
+    }
+
+    pub fn on_refresh<A>(mut self, mut on_refresh: impl FnMut(&mut T) -> A + 'static) -> Self
+    where
+        A: Into<Action>,
+    {
+        self.on_refresh = Some(Box::new(move |data| {
+            on_refresh(data).into()
+        }));
+        self
+    }
+
+    pub fn refreshing(mut self, refreshing: bool) -> Self {
+        self.refreshing = refreshing;
+        self
+    }
+
+    pub fn refresh_indicator<J>(self, indicator: J) -> Scroll<V, T, J> {
+        Scroll {
+            contents:         self.contents,
+            style:            self.style,
+            axes:             self.axes,
+            target:           self.target,
+            animated:         self.animated,
+            stick_to_end:     self.stick_to_end,
+            on_scroll:        self.on_scroll,
+            on_start_reached: self.on_start_reached,
+            on_end_reached:   self.on_end_reached,
+            on_refresh:       self.on_refresh,
+            refreshing:       self.refreshing,
+            indicator:        Some(indicator),
         }
     }
 }
 
-impl<V> Layout for Scroll<V> {
+pub enum NoIndicator {}
+
+impl ViewMarker for NoIndicator {}
+impl<P, T> View<Context<P>, T> for NoIndicator
+where
+    P: HasScroll,
+{
+    type Element = Pod<P::Scroll>;
+    type State = NoIndicator;
+
+    fn build(self, _cx: &mut Context<P>, _data: &mut T) -> (Self::Element, Self::State) {
+        match self {}
+    }
+
+    fn rebuild(
+        self,
+        _element: Mut<'_, Self::Element>,
+        _state: &mut Self::State,
+        _cx: &mut Context<P>,
+        _data: &mut T,
+    ) {
+        match self {}
+    }
+
+    fn message(
+        _element: Mut<'_, Self::Element>,
+        state: &mut Self::State,
+        _cx: &mut Context<P>,
+        _data: &mut T,
+        _message: &mut Message,
+    ) -> Action {
+        match *state {}
+    }
+
+    fn teardown(_element: Self::Element, state: Self::State, _cx: &mut Context<P>) {
+        match state {}
+    }
+}
+
+impl<V, T, I> Layout for Scroll<V, T, I> {
     fn style_mut(&mut self) -> &mut taffy::Style {
         &mut self.style
     }
 }
 
-impl<V> ViewMarker for Scroll<V> {}
-impl<P, T, V> View<Context<P>, T> for Scroll<V>
+impl<V, T, I> ViewMarker for Scroll<V, T, I> {}
+impl<P, T, V, I> View<Context<P>, T> for Scroll<V, T, I>
 where
-    P: HasScroll,
+    P: HasScroll + Proxied,
     V: WidgetView<P, T>,
+    I: WidgetView<P, T>,
 {
     type Element = Pod<P::Scroll>;
-    type State = (V::Element, V::State);
+    type State = (V::Element, ScrollState<P, T, V, I>);
 
     fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
-        let (contents, state) = self.contents.build(cx, data);
+        // the contents know they're scrolled, so that they can wait for the viewport
+        let (contents, state) = cx.with_scroll(|cx| self.contents.build(cx, data));
         let node = cx.new_layout_node(self.style, &[contents.node]);
 
         let mut widget = P::Scroll::build(
@@ -76,11 +304,61 @@ where
             contents.widget.widget(),
         );
 
-        widget.set_direction(self.direction);
+        let view_id = ViewId::next();
+
+        set_axes::<P>(&mut widget, self.axes);
+        widget.set_stick_to_end(self.stick_to_end);
+        widget.set_on_scroll({
+            let proxy = cx.proxy();
+
+            move |offset| {
+                proxy.message(Message::new(
+                    ScrollMessage::Scrolled(offset),
+                    view_id,
+                ));
+            }
+        });
 
-        let pod = Pod { node, widget };
+        widget.set_on_overscroll({
+            let proxy = cx.proxy();
 
-        (pod, (contents, state))
+            move || {
+                proxy.message(Message::new(
+                    ScrollMessage::Overscrolled,
+                    view_id,
+                ));
+            }
+        });
+
+        // the target can only be resolved once the contents have been laid out
+        let mut state = ScrollState {
+            view_id,
+            axes: self.axes,
+            offset: (0.0, 0.0),
+            target: self.target,
+            pending: self.target,
+            animated: self.animated,
+            stick_to_end: self.stick_to_end,
+            on_scroll: self.on_scroll,
+            on_start_reached: self.on_start_reached,
+            on_end_reached: self.on_end_reached,
+            edges: Edges::new(),
+            on_refresh: self.on_refresh,
+            refreshing: false,
+            indicator: None,
+            state,
+        };
+
+        set_refreshing(
+            &mut widget,
+            &mut state,
+            cx,
+            data,
+            self.refreshing,
+            self.indicator,
+        );
+
+        (Pod { node, widget }, (contents, state))
     }
 
     fn rebuild(
@@ -91,13 +369,46 @@ where
         data: &mut T,
     ) {
         let _ = cx.set_layout_style(*element.node, self.style);
-        element.widget.set_direction(self.direction);
 
-        self.contents.rebuild(
-            contents.as_mut(*element.node),
+        if self.axes != state.axes {
+            set_axes::<P>(element.widget, self.axes);
+            state.axes = self.axes;
+        }
+
+        if self.stick_to_end != state.stick_to_end {
+            element.widget.set_stick_to_end(self.stick_to_end);
+            state.stick_to_end = self.stick_to_end;
+        }
+
+        // only scroll when the target changes, so the user isn't fought over the position
+        if self.target != state.target {
+            state.target = self.target;
+            state.pending = self.target;
+            cx.relayout();
+        }
+
+        state.animated = self.animated;
+        state.on_scroll = self.on_scroll;
+        state.on_start_reached = self.on_start_reached;
+        state.on_end_reached = self.on_end_reached;
+        state.on_refresh = self.on_refresh;
+
+        cx.with_scroll(|cx| {
+            self.contents.rebuild(
+                contents.as_mut(*element.node),
+                &mut state.state,
+                cx,
+                data,
+            );
+        });
+
+        set_refreshing(
+            element.widget,
             state,
             cx,
             data,
+            self.refreshing,
+            self.indicator,
         );
     }
 
@@ -108,23 +419,480 @@ where
         data: &mut T,
         message: &mut Message,
     ) -> Action {
-        if let Some(Lifecycle::Layout) = message.get()
-            && let Ok(layout) = cx.get_computed_layout(*element.node)
-        {
+        match message.take_targeted(state.view_id) {
+            Some(ScrollMessage::Scrolled(offset)) => {
+                state.offset = (offset.x, offset.y);
+
+                let mut action = (state.on_scroll)(data, offset);
+                action |= edges_reached(state, data, offset);
+                action |= send_viewport(*element.node, contents, state, cx, data);
+                return action;
+            }
+
+            // pulling past the top only refreshes vertical scrolls, and not while the previous
+            // refresh is still running
+            Some(ScrollMessage::Overscrolled) => {
+                return match state.on_refresh {
+                    Some(ref mut on_refresh) if state.axes.vertical && !state.refreshing => {
+                        on_refresh(data)
+                    }
+
+                    _ => Action::new(),
+                };
+            }
+
+            None => {}
+        }
+
+        // the viewport of an outer scroll is meaningless to the contents of this one
+        if message.get::<ScrollViewport>().is_some() {
+            return Action::new();
+        }
+
+        let is_layout = matches!(message.get(), Some(Lifecycle::Layout));
+
+        let mut action = Action::new();
+
+        if is_layout && let Ok(layout) = cx.get_computed_layout(*element.node) {
             (element.widget).set_size(layout.size.width, layout.size.height);
+
+            let viewport = layout.size;
+
+            // the edges might be reached without any scrolling, when the contents are short
+            if let Ok(content) = cx.get_computed_layout(contents.node) {
+                let offset = ScrollOffset {
+                    x:        state.offset.0,
+                    y:        state.offset.1,
+                    content:  Size {
+                        width:  content.size.width,
+                        height: content.size.height,
+                    },
+                    viewport: Size {
+                        width:  viewport.width,
+                        height: viewport.height,
+                    },
+                };
+
+                action |= edges_reached(state, data, offset);
+            }
+
+            if let Some(target) = state.pending.take()
+                && let Some((x, y)) = resolve_target(cx, contents.node, state.axes, target)
+            {
+                element.widget.scroll_to(x, y, state.animated);
+            }
+        }
+
+        action |= cx.with_scroll(|cx| {
+            V::message(
+                contents.as_mut(*element.node),
+                &mut state.state,
+                cx,
+                data,
+                message,
+            )
+        });
+
+        if let Some((slot, indicator, indicator_state)) = &mut state.indicator {
+            if is_layout {
+                let _ = cx.compute_layout(*slot, taffy::Size::max_content());
+            }
+
+            action |= I::message(
+                indicator.as_mut(*slot),
+                indicator_state,
+                cx,
+                data,
+                message,
+            );
+        }
+
+        if is_layout {
+            action |= send_viewport(*element.node, contents, state, cx, data);
+        }
+
+        action
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        V::teardown(contents, state.state, cx);
+
+        if let Some((slot, indicator, indicator_state)) = state.indicator {
+            I::teardown(indicator, indicator_state, cx);
+            let _ = cx.remove_layout_node(slot);
         }
 
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+enum ScrollMessage {
+    Scrolled(ScrollOffset),
+    Overscrolled,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollViewport {
+    pub contents: taffy::NodeId,
+    pub x:        f32,
+    pub y:        f32,
+    pub width:    f32,
+    pub height:   f32,
+}
+
+impl ScrollViewport {
+    pub fn offset_of<P>(&self, cx: &Context<P>, node: taffy::NodeId) -> Option<(f32, f32)>
+    where
+        P: Platform,
+    {
+        let mut node = node;
+        let mut offset = (0.0, 0.0);
+
+        // walk up the layout tree until the contents of the scroll are reached
+        while node != self.contents {
+            let layout = cx.get_computed_layout(node).ok()?;
+            offset.0 += layout.location.x;
+            offset.1 += layout.location.y;
+
+            node = cx.get_layout_parent(node)?;
+        }
+
+        Some(offset)
+    }
+}
+
+fn edges_reached<P, T, V, I>(
+    state: &mut ScrollState<P, T, V, I>,
+    data: &mut T,
+    offset: ScrollOffset,
+) -> Action
+where
+    P: HasScroll,
+    V: WidgetView<P, T>,
+    I: WidgetView<P, T>,
+{
+    let (offset, viewport, content) = match state.axes.vertical {
+        true => (
+            offset.y,
+            offset.viewport.height,
+            offset.content.height,
+        ),
+        false => (
+            offset.x,
+            offset.viewport.width,
+            offset.content.width,
+        ),
+    };
+
+    let (start, end) = state.edges.update(
+        offset,
+        viewport,
+        content,
+        (
+            state.on_start_reached.0,
+            state.on_end_reached.0,
+        ),
+    );
+
+    let mut action = Action::new();
+
+    if start {
+        action |= (state.on_start_reached.1)(data);
+    }
+
+    if end {
+        action |= (state.on_end_reached.1)(data);
+    }
+
+    action
+}
+
+struct Edges {
+    near_start: bool,
+    near_end:   bool,
+    extent:     f32,
+}
+
+impl Edges {
+    fn new() -> Self {
+        Self {
+            near_start: true,
+            near_end:   false,
+            extent:     0.0,
+        }
+    }
+
+    // returns whether the start and the end were just reached, the callbacks only fire when
+    // crossing the threshold, not on every scroll event past it
+    fn update(
+        &mut self,
+        offset: f32,
+        viewport: f32,
+        content: f32,
+        (start_threshold, end_threshold): (f32, f32),
+    ) -> (bool, bool) {
+        // when the contents grow, the end counts as reached again, so lists that are still too
+        // short keep loading
+        if content > self.extent + 1.0 {
+            self.near_end = false;
+        }
+
+        self.extent = content;
+
+        let near_start = offset <= start_threshold;
+        let near_end = content - (offset + viewport) <= end_threshold;
+
+        let reached = (
+            near_start && !self.near_start,
+            near_end && !self.near_end,
+        );
+
+        self.near_start = near_start;
+        self.near_end = near_end;
+
+        reached
+    }
+}
+
+fn send_viewport<P, T, V, I>(
+    node: taffy::NodeId,
+    contents: &mut V::Element,
+    state: &mut ScrollState<P, T, V, I>,
+    cx: &mut Context<P>,
+    data: &mut T,
+) -> Action
+where
+    P: HasScroll,
+    V: WidgetView<P, T>,
+    I: WidgetView<P, T>,
+{
+    let Ok(layout) = cx.get_computed_layout(node) else {
+        return Action::new();
+    };
+
+    let viewport = ScrollViewport {
+        contents: contents.node,
+        x:        state.offset.0,
+        y:        state.offset.1,
+        width:    layout.size.width,
+        height:   layout.size.height,
+    };
+
+    cx.with_scroll(|cx| {
         V::message(
-            contents.as_mut(*element.node),
-            state,
+            contents.as_mut(node),
+            &mut state.state,
             cx,
             data,
-            message,
+            &mut Message::new(viewport, None),
         )
+    })
+}
+
+fn set_refreshing<P, T, V, I>(
+    widget: &mut P::Scroll,
+    state: &mut ScrollState<P, T, V, I>,
+    cx: &mut Context<P>,
+    data: &mut T,
+    refreshing: bool,
+    indicator: Option<I>,
+) where
+    P: HasScroll,
+    V: WidgetView<P, T>,
+    I: WidgetView<P, T>,
+{
+    // the indicator only exists while refreshing, the platform places it on top of the contents,
+    // so it's laid out on its own rather than as part of the scroll
+    match (
+        refreshing,
+        indicator,
+        &mut state.indicator,
+    ) {
+        (true, Some(view), Some((slot, indicator, indicator_state))) => {
+            view.rebuild(
+                indicator.as_mut(*slot),
+                indicator_state,
+                cx,
+                data,
+            );
+        }
+
+        (true, Some(view), None) => {
+            let (indicator, indicator_state) = view.build(cx, data);
+
+            let slot = cx.new_layout_node(Default::default(), &[indicator.node]);
+
+            widget.set_refreshing(true, Some(indicator.widget.widget()));
+            state.indicator = Some((slot, indicator, indicator_state));
+            state.refreshing = true;
+            return;
+        }
+
+        (_, _, indicator) => {
+            if let Some((slot, indicator, indicator_state)) = indicator.take() {
+                widget.set_refreshing(false, None);
+                I::teardown(indicator, indicator_state, cx);
+                let _ = cx.remove_layout_node(slot);
+                state.refreshing = false;
+            }
+        }
     }
 
-    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
-        V::teardown(contents, state, cx);
-        element.widget.teardown(&mut cx.platform);
+    if refreshing != state.refreshing {
+        widget.set_refreshing(refreshing, None);
+        state.refreshing = refreshing;
+    }
+}
+
+fn set_axes<P>(widget: &mut P::Scroll, axes: ScrollAxes)
+where
+    P: HasScroll,
+{
+    widget.set_scrollbars(
+        axes.horizontal.then_some(axes.hscrollbar),
+        axes.vertical.then_some(axes.vscrollbar),
+    );
+
+    widget.set_kinetic(axes.kinetic);
+}
+
+fn resolve_target<P>(
+    cx: &Context<P>,
+    contents: taffy::NodeId,
+    axes: ScrollAxes,
+    target: ScrollTarget,
+) -> Option<(Option<f32>, Option<f32>)>
+where
+    P: HasScroll,
+{
+    let (x, y) = match target {
+        // a single offset scrolls along the main axis, which is vertical unless only horizontal
+        // scrolling is enabled
+        ScrollTarget::Offset(offset) if axes.vertical => (0.0, offset),
+        ScrollTarget::Offset(offset) => (offset, 0.0),
+        ScrollTarget::Point(x, y) => (x, y),
+
+        ScrollTarget::Child(index) => {
+            let children = cx.get_layout_children(contents).ok()?;
+            let layout = cx.get_computed_layout(*children.get(index)?).ok()?;
+
+            (layout.location.x, layout.location.y)
+        }
+    };
+
+    let offset_x = match target {
+        ScrollTarget::Offset(_) => !axes.vertical,
+        _ => axes.horizontal,
+    };
+
+    Some((
+        offset_x.then_some(x),
+        axes.vertical.then_some(y),
+    ))
+}
+
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub struct ScrollState<P, T, V, I>
+where
+    P: HasScroll,
+    V: WidgetView<P, T>,
+    I: WidgetView<P, T>,
+{
+    view_id:          ViewId,
+    axes:             ScrollAxes,
+    offset:           (f32, f32),
+    target:           Option<ScrollTarget>,
+    pending:          Option<ScrollTarget>,
+    animated:         bool,
+    stick_to_end:     bool,
+    on_scroll:        Box<dyn FnMut(&mut T, ScrollOffset) -> Action>,
+    on_start_reached: (f32, Box<dyn FnMut(&mut T) -> Action>),
+    on_end_reached:   (f32, Box<dyn FnMut(&mut T) -> Action>),
+    edges:            Edges,
+    on_refresh:       Option<Box<dyn FnMut(&mut T) -> Action>>,
+    refreshing:       bool,
+    indicator:        Option<(taffy::NodeId, I::Element, I::State)>,
+    state:            V::State,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLDS: (f32, f32) = (10.0, 10.0);
+
+    #[test]
+    fn edges_start_reached_once() {
+        let mut edges = Edges::new();
+
+        // the start counts as reached from the beginning
+        assert_eq!(
+            edges.update(0.0, 100.0, 500.0, THRESHOLDS),
+            (false, false)
+        );
+        assert_eq!(
+            edges.update(50.0, 100.0, 500.0, THRESHOLDS),
+            (false, false)
+        );
+        assert_eq!(
+            edges.update(5.0, 100.0, 500.0, THRESHOLDS),
+            (true, false)
+        );
+        assert_eq!(
+            edges.update(0.0, 100.0, 500.0, THRESHOLDS),
+            (false, false)
+        );
+    }
+
+    #[test]
+    fn edges_end_reached_once() {
+        let mut edges = Edges::new();
+
+        assert_eq!(
+            edges.update(0.0, 100.0, 500.0, THRESHOLDS),
+            (false, false)
+        );
+        assert_eq!(
+            edges.update(395.0, 100.0, 500.0, THRESHOLDS),
+            (false, true)
+        );
+        assert_eq!(
+            edges.update(400.0, 100.0, 500.0, THRESHOLDS),
+            (false, false)
+        );
+        assert_eq!(
+            edges.update(200.0, 100.0, 500.0, THRESHOLDS),
+            (false, false)
+        );
+        assert_eq!(
+            edges.update(400.0, 100.0, 500.0, THRESHOLDS),
+            (false, true)
+        );
+    }
+
+    #[test]
+    fn edges_end_reached_again_when_contents_grow() {
+        let mut edges = Edges::new();
+
+        // contents shorter than the viewport are at their end right away
+        assert_eq!(
+            edges.update(0.0, 100.0, 50.0, THRESHOLDS),
+            (false, true)
+        );
+        assert_eq!(
+            edges.update(0.0, 100.0, 50.0, THRESHOLDS),
+            (false, false)
+        );
+        assert_eq!(
+            edges.update(0.0, 100.0, 80.0, THRESHOLDS),
+            (false, true)
+        );
+
+        // shrinking contents don't count
+        assert_eq!(
+            edges.update(0.0, 100.0, 70.0, THRESHOLDS),
+            (false, false)
+        );
     }
 }
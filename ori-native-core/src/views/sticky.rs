@@ -0,0 +1,212 @@
+use ori::{Action, Message, Mut, View, ViewMarker};
+
+use crate::{
+    Context, Layout, Lifecycle, NativeWidget, Pod, WidgetView,
+    native::{HasGroup, NativeGroup},
+    views::ScrollViewport,
+};
+
+pub fn sticky<V>(contents: V) -> Sticky<V> {
+    Sticky::new(contents)
+}
+
+pub struct Sticky<V> {
+    contents: V,
+    style:    taffy::Style,
+    top:      f32,
+}
+
+impl<V> Sticky<V> {
+    pub fn new(contents: V) -> Self {
+        Self {
+            contents,
+            style: taffy::Style {
+                display: taffy::Display::Flex,
+                flex_direction: taffy::FlexDirection::Column,
+                flex_shrink: 0.0,
+                ..Default::default()
+            },
+            top: 0.0,
+        }
+    }
+
+    pub fn top(mut self, top: f32) -> Self {
+        self.top = top;
+        self
+    }
+}
+
+impl<V> Layout for Sticky<V> {
+    fn style_mut(&mut self) -> &mut taffy::Style {
+        &mut self.style
+    }
+}
+
+impl<V> ViewMarker for Sticky<V> {}
+impl<P, T, V> View<Context<P>, T> for Sticky<V>
+where
+    P: HasGroup,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P::Group>;
+    type State = (V::Element, StickyState<P, T, V>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let state = StickyState {
+            style: self.style,
+            top: self.top,
+            shift: 0.0,
+            state,
+        };
+
+        let node = cx.new_layout_node(state.sticky_style(), &[contents.node]);
+
+        let mut widget = P::Group::build(&mut cx.platform);
+        widget.insert_child(0, contents.widget.widget());
+
+        (Pod { node, widget }, (contents, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        state.style = self.style;
+        state.top = self.top;
+
+        let _ = cx.set_layout_style(*element.node, state.sticky_style());
+
+        self.contents.rebuild(
+            contents.as_mut(*element.node),
+            &mut state.state,
+            cx,
+            data,
+        );
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(viewport) = message.get::<ScrollViewport>() {
+            let shift = state.resolve_shift(cx, *element.node, viewport);
+            let was_stuck = state.shift > 0.0;
+
+            if (shift - state.shift).abs() > 0.1 {
+                state.shift = shift;
+                let _ = cx.set_layout_style(*element.node, state.sticky_style());
+            }
+
+            // siblings after the header would otherwise be drawn over it while it is stuck
+            let stuck = state.shift > 0.0;
+
+            if stuck != was_stuck {
+                element.widget.set_raised(stuck);
+            }
+        }
+
+        if let Some(Lifecycle::Layout) = message.get() {
+            if let Ok(layout) = cx.get_computed_layout(*element.node) {
+                (element.widget).set_size(layout.size.width, layout.size.height);
+            }
+
+            if let Ok(layout) = cx.get_computed_layout(contents.node) {
+                element.widget.set_child_layout(
+                    0,
+                    layout.location.x,
+                    layout.location.y,
+                    layout.size.width,
+                    layout.size.height,
+                );
+            }
+        }
+
+        V::message(
+            contents.as_mut(*element.node),
+            &mut state.state,
+            cx,
+            data,
+            message,
+        )
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        V::teardown(contents, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(element.node);
+    }
+}
+
+#[doc(hidden)]
+pub struct StickyState<P, T, V>
+where
+    P: HasGroup,
+    V: WidgetView<P, T>,
+{
+    style: taffy::Style,
+    top:   f32,
+    shift: f32,
+    state: V::State,
+}
+
+impl<P, T, V> StickyState<P, T, V>
+where
+    P: HasGroup,
+    V: WidgetView<P, T>,
+{
+    fn sticky_style(&self) -> taffy::Style {
+        let mut style = self.style.clone();
+        style.position = taffy::Position::Relative;
+        style.inset.top = taffy::LengthPercentageAuto::length(self.shift);
+        style.inset.bottom = taffy::LengthPercentageAuto::auto();
+        style
+    }
+
+    fn resolve_shift(
+        &self,
+        cx: &Context<P>,
+        node: taffy::NodeId,
+        viewport: &ScrollViewport,
+    ) -> f32 {
+        let Some((_, y)) = viewport.offset_of(cx, node) else {
+            return 0.0;
+        };
+
+        let Ok(layout) = cx.get_computed_layout(node) else {
+            return 0.0;
+        };
+
+        // the computed location includes the current shift, remove it to get the position the
+        // header would have in the flow of its parent
+        let natural = y - self.shift;
+        let height = layout.size.height;
+
+        // the header sticks to the top of the viewport, but never leaves the section it heads,
+        // so the next header pushes it out of the way
+        let limit = match cx.get_layout_parent(node) {
+            Some(parent) if parent != viewport.contents => {
+                let Some((_, top)) = viewport.offset_of(cx, parent) else {
+                    return 0.0;
+                };
+
+                let Ok(section) = cx.get_computed_layout(parent) else {
+                    return 0.0;
+                };
+
+                top + section.size.height - natural - height
+            }
+
+            _ => f32::INFINITY,
+        };
+
+        (viewport.y + self.top - natural).min(limit).max(0.0)
+    }
+}
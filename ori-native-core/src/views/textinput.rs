@@ -34,6 +34,7 @@ pub struct TextInput<T> {
 
     newline:    Newline,
     accept_tab: bool,
+    disabled:   bool,
     on_change:  Box<dyn FnMut(&mut T, String) -> Action>,
     on_submit:  Box<dyn FnMut(&mut T, String) -> Action>,
 }
@@ -65,6 +66,7 @@ impl<T> TextInput<T> {
 
             newline:    Newline::Enter,
             accept_tab: true,
+            disabled:   false,
             on_change:  Box::new(|_, _| Action::new()),
             on_submit:  Box::new(|_, _| Action::new()),
         }
@@ -166,6 +168,11 @@ impl<T> TextInput<T> {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
     pub fn on_change<A>(mut self, mut on_change: impl FnMut(&mut T, String) -> A + 'static) -> Self
     where
         A: Into<Action>,
@@ -223,6 +230,7 @@ where
 
         widget.set_newline(&mut cx.platform, self.newline);
         widget.set_accept_tab(&mut cx.platform, self.accept_tab);
+        widget.set_disabled(&mut cx.platform, self.disabled);
 
         let layout = widget.get_layout(&mut cx.platform);
         let node = cx.new_layout_leaf(self.layout, layout);
@@ -255,6 +263,7 @@ where
 
             newline: self.newline,
             accept_tab: self.accept_tab,
+            disabled: self.disabled,
 
             view_id,
             on_change: self.on_change,
@@ -315,6 +324,11 @@ where
                 .set_accept_tab(&mut cx.platform, self.accept_tab);
         }
 
+        if self.disabled != state.disabled {
+            state.disabled = self.disabled;
+            element.widget.set_disabled(&mut cx.platform, self.disabled);
+        }
+
         if changed {
             let layout = element.widget.get_layout(&mut cx.platform);
             let _ = cx.set_leaf_layout(*element.node, layout);
@@ -335,9 +349,14 @@ where
             match message {
                 TextInputMessage::Change(text) => {
                     state.text = text.clone();
-                    (state.on_change)(data, text)
+
+                    match state.disabled {
+                        true => Action::new(),
+                        false => (state.on_change)(data, text),
+                    }
                 }
 
+                TextInputMessage::Submit(_) if state.disabled => Action::new(),
                 TextInputMessage::Submit(text) => (state.on_submit)(data, text),
             }
         } else {
@@ -362,6 +381,7 @@ pub struct TextInputState<T> {
 
     newline:    Newline,
     accept_tab: bool,
+    disabled:   bool,
 
     view_id:   ViewId,
     on_change: Box<dyn FnMut(&mut T, String) -> Action>,
@@ -0,0 +1,298 @@
+use std::time::Duration;
+
+use ori::{Action, Message, Mut, View, ViewMarker};
+
+use crate::{
+    Context, Lifecycle, NativeWidget, Pod, PodMut, WidgetView,
+    native::{HasTooltip, NativeTooltip},
+};
+
+pub fn tooltip<V, C>(contents: V, content: C) -> Tooltip<V, C> {
+    Tooltip::new(contents, content)
+}
+
+pub fn tooltip_text<V>(contents: V, text: impl Into<String>) -> TooltipText<V> {
+    TooltipText::new(contents, text)
+}
+
+pub struct Tooltip<V, C> {
+    contents: V,
+    content:  C,
+    delay:    Duration,
+}
+
+impl<V, C> Tooltip<V, C> {
+    pub fn new(contents: V, content: C) -> Self {
+        Self {
+            contents,
+            content,
+            delay: Duration::from_millis(500),
+        }
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+impl<V, C> ViewMarker for Tooltip<V, C> {}
+impl<P, T, V, C> View<Context<P>, T> for Tooltip<V, C>
+where
+    P: HasTooltip,
+    V: WidgetView<P, T>,
+    C: WidgetView<P, T>,
+{
+    type Element = Pod<P::Tooltip>;
+    type State = (V::Widget, TooltipState<P, T, V, C>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+        let (content, content_state) = self.content.build(cx, data);
+
+        // sizing the tooltip must not move the contents, so it gets a layout root of its own
+        let node = cx.new_layout_node(Default::default(), &[content.node]);
+
+        let mut widget = P::Tooltip::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+            Some(content.widget.widget()),
+        );
+
+        widget.set_delay(self.delay);
+
+        let pod = Pod {
+            node: contents.node,
+            widget,
+        };
+
+        let state = TooltipState {
+            delay: self.delay,
+            node,
+            content,
+            content_state,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.delay != state.delay {
+            element.widget.set_delay(self.delay);
+            state.delay = self.delay;
+        }
+
+        (self.content).rebuild(
+            state.content.as_mut(state.node),
+            &mut state.content_state,
+            cx,
+            data,
+        );
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get() {
+            if let Ok(layout) = cx.get_computed_layout(*element.node) {
+                (element.widget).set_size(layout.size.width, layout.size.height);
+            }
+
+            let _ = cx.compute_layout(state.node, taffy::Size::max_content());
+
+            if let Ok(layout) = cx.get_computed_layout(state.content.node) {
+                (element.widget).set_content_size(layout.size.width, layout.size.height);
+            }
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        let mut action = V::message(pod, &mut state.state, cx, data, message);
+
+        action |= C::message(
+            state.content.as_mut(state.node),
+            &mut state.content_state,
+            cx,
+            data,
+            message,
+        );
+
+        action
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod {
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::teardown(pod, state.state, cx);
+        C::teardown(state.content, state.content_state, cx);
+
+        element.widget.teardown(&mut cx.platform);
+        let _ = cx.remove_layout_node(state.node);
+    }
+}
+
+#[doc(hidden)]
+pub struct TooltipState<P, T, V, C>
+where
+    P: HasTooltip,
+    V: WidgetView<P, T>,
+    C: WidgetView<P, T>,
+{
+    delay:         Duration,
+    node:          taffy::NodeId,
+    content:       Pod<C::Widget>,
+    content_state: C::State,
+    state:         V::State,
+}
+
+pub struct TooltipText<V> {
+    contents: V,
+    text:     String,
+    delay:    Duration,
+}
+
+impl<V> TooltipText<V> {
+    pub fn new(contents: V, text: impl Into<String>) -> Self {
+        Self {
+            contents,
+            text: text.into(),
+            delay: Duration::from_millis(500),
+        }
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+impl<V> ViewMarker for TooltipText<V> {}
+impl<P, T, V> View<Context<P>, T> for TooltipText<V>
+where
+    P: HasTooltip,
+    V: WidgetView<P, T>,
+{
+    type Element = Pod<P::Tooltip>;
+    type State = (V::Widget, TooltipTextState<P, T, V>);
+
+    fn build(self, cx: &mut Context<P>, data: &mut T) -> (Self::Element, Self::State) {
+        let (contents, state) = self.contents.build(cx, data);
+
+        let mut widget = P::Tooltip::build(
+            &mut cx.platform,
+            contents.widget.widget(),
+            None,
+        );
+
+        widget.set_text(Some(self.text.clone()));
+        widget.set_delay(self.delay);
+
+        let pod = Pod {
+            node: contents.node,
+            widget,
+        };
+
+        let state = TooltipTextState {
+            text: self.text,
+            delay: self.delay,
+            state,
+        };
+
+        (pod, (contents.widget, state))
+    }
+
+    fn rebuild(
+        self,
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+    ) {
+        if self.text != state.text {
+            element.widget.set_text(Some(self.text.clone()));
+            state.text = self.text;
+        }
+
+        if self.delay != state.delay {
+            element.widget.set_delay(self.delay);
+            state.delay = self.delay;
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        self.contents.rebuild(pod, &mut state.state, cx, data);
+    }
+
+    fn message(
+        element: Mut<'_, Self::Element>,
+        (contents, state): &mut Self::State,
+        cx: &mut Context<P>,
+        data: &mut T,
+        message: &mut Message,
+    ) -> Action {
+        if let Some(Lifecycle::Layout) = message.get()
+            && let Ok(layout) = cx.get_computed_layout(*element.node)
+        {
+            (element.widget).set_size(layout.size.width, layout.size.height);
+        }
+
+        let pod = PodMut {
+            parent: element.parent,
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::message(pod, &mut state.state, cx, data, message)
+    }
+
+    fn teardown(element: Self::Element, (contents, state): Self::State, cx: &mut Context<P>) {
+        let pod = Pod {
+            node:   element.node,
+            widget: contents,
+        };
+
+        V::teardown(pod, state.state, cx);
+        element.widget.teardown(&mut cx.platform);
+    }
+}
+
+#[doc(hidden)]
+pub struct TooltipTextState<P, T, V>
+where
+    P: HasTooltip,
+    V: WidgetView<P, T>,
+{
+    text:  String,
+    delay: Duration,
+    state: V::State,
+}
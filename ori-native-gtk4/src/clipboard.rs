@@ -0,0 +1,127 @@
+use gdk4::prelude::{TextureExt, TextureExtManual};
+use gio::prelude::{MemoryOutputStreamExt, OutputStreamExt};
+use ori_native_core::{
+    RgbaImage,
+    native::{ClipboardContent, ClipboardFormat, HasClipboard},
+};
+
+use crate::Platform;
+
+impl HasClipboard for Platform {
+    fn write_clipboard(&mut self, content: ClipboardContent) {
+        let clipboard = self.display.clipboard();
+
+        match content {
+            ClipboardContent::Text(text) => clipboard.set_text(&text),
+
+            ClipboardContent::Image(image) => {
+                let texture = gdk4::MemoryTexture::new(
+                    image.width as i32,
+                    image.height as i32,
+                    gdk4::MemoryFormat::R8g8b8a8,
+                    &glib::Bytes::from(&image.pixels[..]),
+                    image.width as usize * 4,
+                );
+
+                clipboard.set_texture(&texture);
+            }
+
+            ClipboardContent::Custom { mime_type, data } => {
+                let provider = gdk4::ContentProvider::for_bytes(
+                    &mime_type,
+                    &glib::Bytes::from_owned(data),
+                );
+
+                if let Err(err) = clipboard.set_content(Some(&provider)) {
+                    tracing::warn!("failed to write clipboard: {err}");
+                }
+            }
+        }
+    }
+
+    fn read_clipboard(
+        &mut self,
+        format: ClipboardFormat,
+        on_read: impl FnOnce(Option<ClipboardContent>) + 'static,
+    ) {
+        let clipboard = self.display.clipboard();
+
+        match format {
+            ClipboardFormat::Text => {
+                clipboard.read_text_async(
+                    None::<&gio::Cancellable>,
+                    move |result| {
+                        let text = result.ok().flatten();
+                        on_read(text.map(|text| ClipboardContent::Text(text.into())));
+                    },
+                );
+            }
+
+            ClipboardFormat::Image => {
+                clipboard.read_texture_async(
+                    None::<&gio::Cancellable>,
+                    move |result| {
+                        let texture = result.ok().flatten();
+                        on_read(
+                            texture.map(|texture| ClipboardContent::Image(rgba_image(&texture))),
+                        );
+                    },
+                );
+            }
+
+            ClipboardFormat::Custom(mime_type) => {
+                clipboard.read_async(
+                    &[&*mime_type.clone()],
+                    glib::Priority::DEFAULT,
+                    None::<&gio::Cancellable>,
+                    move |result| {
+                        let Ok((input, _)) = result else {
+                            return on_read(None);
+                        };
+
+                        // the data arrives as a stream, collect it into memory before handing it
+                        // to the view
+                        let output = gio::MemoryOutputStream::new_resizable();
+                        output.clone().splice_async(
+                            &input,
+                            gio::OutputStreamSpliceFlags::CLOSE_SOURCE
+                                | gio::OutputStreamSpliceFlags::CLOSE_TARGET,
+                            glib::Priority::DEFAULT,
+                            None::<&gio::Cancellable>,
+                            move |result| match result {
+                                Ok(_) => on_read(Some(ClipboardContent::Custom {
+                                    mime_type,
+                                    data: output.steal_as_bytes().to_vec(),
+                                })),
+
+                                Err(_) => on_read(None),
+                            },
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+// textures download as premultiplied bgra, the clipboard hands out straight rgba
+fn rgba_image(texture: &gdk4::Texture) -> RgbaImage {
+    let width = texture.width() as u32;
+    let height = texture.height() as u32;
+    let stride = width as usize * 4;
+
+    let mut pixels = vec![0; stride * height as usize];
+    texture.download(&mut pixels, stride);
+
+    for pixel in pixels.chunks_exact_mut(4) {
+        let [b, g, r, a] = [pixel[0], pixel[1], pixel[2], pixel[3]];
+        let unpremultiply = |c: u8| match a {
+            0 => 0,
+            a => (c as u32 * 255 / a as u32).min(255) as u8,
+        };
+
+        pixel.copy_from_slice(&[unpremultiply(r), unpremultiply(g), unpremultiply(b), a]);
+    }
+
+    RgbaImage::new(width, height, pixels)
+}
@@ -1,4 +1,5 @@
 mod application;
+mod clipboard;
 mod platform;
 
 pub mod views;
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, pin::Pin, sync::Arc};
+use std::{any::TypeId, cell::Cell, collections::HashMap, pin::Pin, rc::Rc, sync::Arc};
 
 use ori::{Message, Proxied, Proxy};
 use tokio::sync::mpsc::UnboundedSender;
@@ -20,6 +20,7 @@ pub struct Platform {
     pub(crate) application:   gtk4::Application,
     pub(crate) css_providers: HashMap<StyleNode, gtk4::CssProvider>,
     pub(crate) next_css_node: u64,
+    pub(crate) drag_payload:  Rc<Cell<Option<TypeId>>>,
 }
 
 impl Platform {
@@ -45,6 +46,7 @@ impl Platform {
             application,
             css_providers: HashMap::new(),
             next_css_node: 0,
+            drag_payload: Rc::new(Cell::new(None)),
         }
     }
 
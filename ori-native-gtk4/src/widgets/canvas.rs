@@ -0,0 +1,428 @@
+use std::rc::Rc;
+
+use glib::subclass::types::ObjectSubclassIsExt;
+use gtk4::{cairo, prelude::WidgetExt};
+use ori_native_core::{
+    DrawCommand, FillRule, LineCap, LineJoin, NativeWidget, Paint, Path, PathElement, RgbaImage,
+    native::{HasCanvas, NativeCanvas},
+};
+
+use crate::Platform;
+
+impl HasCanvas for Platform {
+    type Canvas = Canvas;
+}
+
+pub struct Canvas {
+    canvas: CanvasWidget,
+}
+
+impl NativeWidget<Platform> for Canvas {
+    fn widget(&self) -> &gtk4::Widget {
+        self.canvas.as_ref()
+    }
+}
+
+impl NativeCanvas<Platform> for Canvas {
+    fn build(_platform: &mut Platform) -> Self {
+        Self {
+            canvas: CanvasWidget::new(),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.canvas.set_size(
+            width.round() as i32,
+            height.round() as i32,
+        );
+    }
+
+    fn draw(&mut self, _platform: &mut Platform, commands: Rc<[DrawCommand]>) {
+        self.canvas.set_commands(commands);
+    }
+}
+
+glib::wrapper! {
+    pub struct CanvasWidget(
+        ObjectSubclass<imp::CanvasWidget>)
+        @extends
+            gtk4::Widget,
+        @implements
+            gtk4::Buildable,
+            gtk4::Accessible,
+            gtk4::ConstraintTarget;
+}
+
+impl CanvasWidget {
+    pub fn new() -> Self {
+        gtk4::glib::Object::builder().build()
+    }
+
+    pub fn set_size(&self, width: i32, height: i32) {
+        if self.imp().width.get() != width || self.imp().height.get() != height {
+            self.imp().width.set(width);
+            self.imp().height.set(height);
+            self.queue_resize();
+        }
+    }
+
+    pub fn set_commands(&self, commands: Rc<[DrawCommand]>) {
+        // images are converted to cairo surfaces once, instead of on every snapshot
+        let surfaces = commands
+            .iter()
+            .map(|command| match command {
+                DrawCommand::Image { image, .. } => image_surface(image),
+                _ => None,
+            })
+            .collect();
+
+        self.imp().commands.replace(commands);
+        self.imp().surfaces.replace(surfaces);
+        self.queue_draw();
+    }
+}
+
+fn replay(cr: &cairo::Context, commands: &[DrawCommand], surfaces: &[Option<cairo::ImageSurface>]) {
+    for (command, surface) in commands.iter().zip(surfaces) {
+        match command {
+            DrawCommand::Fill { path, paint, rule } => {
+                append_path(cr, path);
+                set_paint(cr, paint);
+
+                cr.set_fill_rule(match rule {
+                    FillRule::NonZero => cairo::FillRule::Winding,
+                    FillRule::EvenOdd => cairo::FillRule::EvenOdd,
+                });
+
+                let _ = cr.fill();
+            }
+
+            DrawCommand::Stroke {
+                path,
+                paint,
+                stroke,
+            } => {
+                append_path(cr, path);
+                set_paint(cr, paint);
+
+                cr.set_line_width(stroke.width as f64);
+
+                cr.set_line_cap(match stroke.cap {
+                    LineCap::Butt => cairo::LineCap::Butt,
+                    LineCap::Round => cairo::LineCap::Round,
+                    LineCap::Square => cairo::LineCap::Square,
+                });
+
+                cr.set_line_join(match stroke.join {
+                    LineJoin::Miter => cairo::LineJoin::Miter,
+                    LineJoin::Round => cairo::LineJoin::Round,
+                    LineJoin::Bevel => cairo::LineJoin::Bevel,
+                });
+
+                let dash: Vec<f64> = stroke.dash.iter().map(|&x| x as f64).collect();
+                cr.set_dash(&dash, 0.0);
+
+                let _ = cr.stroke();
+            }
+
+            DrawCommand::Text { x, y, text, font } => {
+                let layout = pangocairo::functions::create_layout(cr);
+                let desc = super::text::font_description(font);
+
+                layout.set_font_description(Some(&desc));
+                layout.set_text(text);
+
+                if font.striketrough {
+                    let attrs = pango::AttrList::new();
+                    attrs.insert(pango::AttrInt::new_strikethrough(true));
+                    layout.set_attributes(Some(&attrs));
+                }
+
+                cr.set_source_rgba(
+                    font.color.r as f64,
+                    font.color.g as f64,
+                    font.color.b as f64,
+                    font.color.a as f64,
+                );
+
+                cr.move_to(*x as f64, *y as f64);
+                pangocairo::functions::show_layout(cr, &layout);
+            }
+
+            DrawCommand::Image {
+                x,
+                y,
+                width,
+                height,
+                image,
+            } => {
+                let Some(surface) = surface else {
+                    continue;
+                };
+
+                if image.width == 0 || image.height == 0 || cr.save().is_err() {
+                    continue;
+                }
+
+                cr.translate(*x as f64, *y as f64);
+                cr.scale(
+                    *width as f64 / image.width as f64,
+                    *height as f64 / image.height as f64,
+                );
+
+                let _ = cr.set_source_surface(surface, 0.0, 0.0);
+                let _ = cr.paint();
+                let _ = cr.restore();
+            }
+
+            DrawCommand::Save => {
+                let _ = cr.save();
+            }
+
+            DrawCommand::Restore => {
+                let _ = cr.restore();
+            }
+
+            DrawCommand::Transform(affine) => {
+                cr.transform(cairo::Matrix::new(
+                    affine.xx as f64,
+                    affine.yx as f64,
+                    affine.xy as f64,
+                    affine.yy as f64,
+                    affine.x0 as f64,
+                    affine.y0 as f64,
+                ));
+            }
+
+            DrawCommand::Clip(path) => {
+                append_path(cr, path);
+                cr.clip();
+            }
+        }
+    }
+}
+
+fn append_path(cr: &cairo::Context, path: &Path) {
+    cr.new_path();
+
+    for element in path.elements() {
+        match *element {
+            PathElement::MoveTo(x, y) => cr.move_to(x as f64, y as f64),
+            PathElement::LineTo(x, y) => cr.line_to(x as f64, y as f64),
+
+            // cairo has no quadratic curves, so they are elevated to cubic ones
+            PathElement::QuadTo(x1, y1, x, y) => {
+                let (x0, y0) = cr.current_point().unwrap_or((x1 as f64, y1 as f64));
+                let (x1, y1) = (x1 as f64, y1 as f64);
+                let (x, y) = (x as f64, y as f64);
+
+                cr.curve_to(
+                    x0 + 2.0 / 3.0 * (x1 - x0),
+                    y0 + 2.0 / 3.0 * (y1 - y0),
+                    x + 2.0 / 3.0 * (x1 - x),
+                    y + 2.0 / 3.0 * (y1 - y),
+                    x,
+                    y,
+                );
+            }
+
+            PathElement::CubicTo(x1, y1, x2, y2, x, y) => cr.curve_to(
+                x1 as f64, y1 as f64, x2 as f64, y2 as f64, x as f64, y as f64,
+            ),
+
+            PathElement::Close => cr.close_path(),
+        }
+    }
+}
+
+fn set_paint(cr: &cairo::Context, paint: &Paint) {
+    match paint {
+        Paint::Color(color) => cr.set_source_rgba(
+            color.r as f64,
+            color.g as f64,
+            color.b as f64,
+            color.a as f64,
+        ),
+
+        Paint::LinearGradient { start, end, stops } => {
+            let gradient = cairo::LinearGradient::new(
+                start.0 as f64,
+                start.1 as f64,
+                end.0 as f64,
+                end.1 as f64,
+            );
+
+            for stop in stops {
+                gradient.add_color_stop_rgba(
+                    stop.offset as f64,
+                    stop.color.r as f64,
+                    stop.color.g as f64,
+                    stop.color.b as f64,
+                    stop.color.a as f64,
+                );
+            }
+
+            let _ = cr.set_source(&gradient);
+        }
+
+        Paint::RadialGradient {
+            center,
+            radius,
+            stops,
+        } => {
+            let gradient = cairo::RadialGradient::new(
+                center.0 as f64,
+                center.1 as f64,
+                0.0,
+                center.0 as f64,
+                center.1 as f64,
+                *radius as f64,
+            );
+
+            for stop in stops {
+                gradient.add_color_stop_rgba(
+                    stop.offset as f64,
+                    stop.color.r as f64,
+                    stop.color.g as f64,
+                    stop.color.b as f64,
+                    stop.color.a as f64,
+                );
+            }
+
+            let _ = cr.set_source(&gradient);
+        }
+    }
+}
+
+fn image_surface(image: &RgbaImage) -> Option<cairo::ImageSurface> {
+    let width = image.width as usize;
+    let height = image.height as usize;
+
+    // images that don't match their buffer are skipped rather than drawn partially
+    if width == 0 || height == 0 || image.pixels.len() != width * height * 4 {
+        return None;
+    }
+
+    let mut surface = cairo::ImageSurface::create(
+        cairo::Format::ARgb32,
+        image.width as i32,
+        image.height as i32,
+    )
+    .ok()?;
+
+    let stride = surface.stride() as usize;
+
+    {
+        let mut data = surface.data().ok()?;
+
+        for (y, row) in image
+            .pixels
+            .chunks_exact(width * 4)
+            .take(height)
+            .enumerate()
+        {
+            for (x, pixel) in row.chunks_exact(4).enumerate() {
+                let [r, g, b, a] = [pixel[0], pixel[1], pixel[2], pixel[3]];
+                let premultiply = |c: u8| (c as u32 * a as u32 / 255) as u8;
+
+                // cairo stores pixels as premultiplied, native endian 0xAARRGGBB
+                let argb = u32::from_be_bytes([a, premultiply(r), premultiply(g), premultiply(b)]);
+
+                let offset = y * stride + x * 4;
+                data[offset..offset + 4].copy_from_slice(&argb.to_ne_bytes());
+            }
+        }
+    }
+
+    surface.mark_dirty();
+
+    Some(surface)
+}
+
+mod imp {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+
+    use glib::subclass::{
+        object::ObjectImpl,
+        types::{ObjectSubclass, ObjectSubclassExt},
+    };
+    use gtk4::{
+        cairo,
+        prelude::{SnapshotExt, WidgetExt},
+        subclass::widget::{WidgetClassExt, WidgetImpl},
+    };
+    use ori_native_core::DrawCommand;
+
+    pub struct CanvasWidget {
+        pub(super) commands: RefCell<Rc<[DrawCommand]>>,
+        pub(super) surfaces: RefCell<Vec<Option<cairo::ImageSurface>>>,
+
+        pub(super) width:  Cell<i32>,
+        pub(super) height: Cell<i32>,
+    }
+
+    impl Default for CanvasWidget {
+        fn default() -> Self {
+            Self {
+                commands: RefCell::new(Rc::from([])),
+                surfaces: RefCell::default(),
+
+                width:  Cell::new(0),
+                height: Cell::new(0),
+            }
+        }
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for CanvasWidget {
+        const NAME: &'static str = "OriCanvas";
+        type Type = super::CanvasWidget;
+        type ParentType = gtk4::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.set_css_name("canvas");
+        }
+    }
+
+    impl ObjectImpl for CanvasWidget {}
+
+    impl WidgetImpl for CanvasWidget {
+        fn snapshot(&self, snapshot: &gtk4::Snapshot) {
+            let alloc = self.obj().allocation();
+
+            let cr = snapshot.append_cairo(&graphene::Rect::new(
+                0.0,
+                0.0,
+                alloc.width() as f32,
+                alloc.height() as f32,
+            ));
+
+            super::replay(
+                &cr,
+                &self.commands.borrow(),
+                &self.surfaces.borrow(),
+            );
+        }
+
+        fn measure(&self, orientation: gtk4::Orientation, _for_size: i32) -> (i32, i32, i32, i32) {
+            match orientation {
+                gtk4::Orientation::Horizontal => {
+                    let width = self.width.get();
+                    (width, width, -1, -1)
+                }
+
+                gtk4::Orientation::Vertical => {
+                    let height = self.height.get();
+                    (height, height, -1, -1)
+                }
+
+                _ => (-1, -1, -1, -1),
+            }
+        }
+    }
+}
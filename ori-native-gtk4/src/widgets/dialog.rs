@@ -0,0 +1,179 @@
+use std::rc::Rc;
+
+use gio::prelude::CancellableExt;
+use glib::object::{CastNone, ObjectExt};
+use gtk4::prelude::{GtkWindowExt, WidgetExt};
+use ori_native_core::{
+    NativeWidget,
+    native::{HasAlert, HasDialog, NativeAlert, NativeDialog},
+};
+
+use crate::{Platform, widgets::group::GroupWidget};
+
+impl HasDialog for Platform {
+    type Dialog = Dialog;
+}
+
+pub struct Dialog {
+    placeholder: GroupWidget,
+    window:      gtk4::Window,
+    content:     gtk4::Widget,
+    on_close:    Option<glib::SignalHandlerId>,
+}
+
+impl NativeWidget<Platform> for Dialog {
+    fn widget(&self) -> &gtk4::Widget {
+        self.placeholder.as_ref()
+    }
+}
+
+impl NativeDialog<Platform> for Dialog {
+    fn build(_platform: &mut Platform, content: &gtk4::Widget) -> Self {
+        let placeholder = GroupWidget::new();
+
+        let window = gtk4::Window::new();
+        window.set_modal(true);
+        window.set_resizable(false);
+        window.set_destroy_with_parent(true);
+        window.set_child(Some(content));
+
+        Self {
+            placeholder,
+            window,
+            content: content.clone(),
+            on_close: None,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {
+        self.window.set_child(None::<&gtk4::Widget>);
+        self.window.destroy();
+    }
+
+    fn set_title(&mut self, title: &str) {
+        self.window.set_title(Some(title));
+    }
+
+    fn set_content_size(&mut self, width: f32, height: f32) {
+        self.content.set_size_request(width as i32, height as i32);
+    }
+
+    fn set_open(&mut self, open: bool) {
+        if !open {
+            self.window.set_visible(false);
+            return;
+        }
+
+        let window = self.window.downgrade();
+        with_parent(&self.placeholder, move |parent| {
+            if let Some(window) = window.upgrade() {
+                window.set_transient_for(parent.as_ref());
+                window.present();
+            }
+        });
+    }
+
+    fn set_on_close_requested(&mut self, on_close_requested: impl Fn() + 'static) {
+        if let Some(handler) = self.on_close.take() {
+            self.window.disconnect(handler);
+        }
+
+        // the view decides whether the dialog closes
+        let handler = self.window.connect_close_request(move |_| {
+            on_close_requested();
+            glib::Propagation::Stop
+        });
+
+        self.on_close = Some(handler);
+    }
+}
+
+impl HasAlert for Platform {
+    type Alert = Alert;
+}
+
+pub struct Alert {
+    placeholder: GroupWidget,
+    cancellable: Option<gio::Cancellable>,
+}
+
+impl NativeWidget<Platform> for Alert {
+    fn widget(&self) -> &gtk4::Widget {
+        self.placeholder.as_ref()
+    }
+}
+
+impl NativeAlert<Platform> for Alert {
+    fn build(_platform: &mut Platform) -> Self {
+        Self {
+            placeholder: GroupWidget::new(),
+            cancellable: None,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {
+        if let Some(cancellable) = self.cancellable {
+            cancellable.cancel();
+        }
+    }
+
+    fn show(
+        &mut self,
+        title: &str,
+        message: &str,
+        buttons: &[String],
+        on_response: impl Fn(Option<usize>) + 'static,
+    ) {
+        if let Some(cancellable) = self.cancellable.take() {
+            cancellable.cancel();
+        }
+
+        let cancellable = gio::Cancellable::new();
+        self.cancellable = Some(cancellable.clone());
+
+        let dialog = gtk4::AlertDialog::builder()
+            .modal(true)
+            .message(title)
+            .detail(message)
+            .buttons(buttons.to_vec())
+            .build();
+
+        let on_response = Rc::new(on_response);
+
+        with_parent(&self.placeholder, move |parent| {
+            dialog.choose(parent.as_ref(), Some(&cancellable), {
+                let cancellable = cancellable.clone();
+
+                move |result| {
+                    // the dialog was closed from our side, there's no one to answer
+                    if cancellable.is_cancelled() {
+                        return;
+                    }
+
+                    on_response(result.ok().map(|index| index as usize));
+                }
+            });
+        });
+    }
+}
+
+// the placeholder only knows its window once it's been added to it, which for newly built views
+// is after they're built
+pub(crate) fn with_parent(
+    placeholder: &GroupWidget,
+    f: impl FnOnce(Option<gtk4::Window>) + 'static,
+) {
+    let parent = |placeholder: &GroupWidget| placeholder.root().and_downcast::<gtk4::Window>();
+
+    if let Some(parent) = parent(placeholder) {
+        return f(Some(parent));
+    }
+
+    let placeholder = placeholder.downgrade();
+    glib::idle_add_local_once(move || {
+        let parent = placeholder
+            .upgrade()
+            .and_then(|placeholder| parent(&placeholder));
+        f(parent);
+    });
+}
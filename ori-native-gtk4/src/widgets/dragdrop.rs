@@ -0,0 +1,363 @@
+use std::{
+    any::TypeId,
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use gdk4::prelude::PaintableExt;
+use gio::prelude::FileExt;
+use glib::{object::ObjectExt, types::StaticType, value::ToValue};
+use gtk4::prelude::{EventControllerExt, WidgetExt};
+use ori_native_core::{
+    NativeWidget, Point,
+    native::{
+        DropAccepts, DropData, DropEvent, DropKind, DroppedFile, HasDragSource, HasDropTarget,
+        NativeDragSource, NativeDropTarget,
+    },
+};
+
+use crate::{
+    Platform,
+    widgets::{group::GroupWidget, key::propagation_phase},
+};
+
+// in-app payloads never leave the process, the mime type only marks the drag as ours, the
+// payload itself is handed over by the views, and its type is tracked by the platform
+const APP_MIME_TYPE: &str = "application/x-ori-native-payload";
+
+impl HasDragSource for Platform {
+    type DragSource = DragSource;
+}
+
+pub struct DragSource {
+    widget:  GroupWidget,
+    source:  gtk4::DragSource,
+    text:    Rc<RefCell<Option<String>>>,
+    payload: Rc<Cell<Option<TypeId>>>,
+    on_drag: Vec<glib::SignalHandlerId>,
+}
+
+impl NativeWidget<Platform> for DragSource {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeDragSource<Platform> for DragSource {
+    fn build(platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        let text = Rc::new(RefCell::new(None::<String>));
+        let payload = Rc::new(Cell::new(None::<TypeId>));
+
+        let source = gtk4::DragSource::new();
+        source.set_actions(gdk4::DragAction::COPY);
+
+        source.connect_prepare({
+            let text = text.clone();
+
+            move |_, _, _| {
+                let mut providers = vec![gdk4::ContentProvider::for_bytes(
+                    APP_MIME_TYPE,
+                    &glib::Bytes::from_static(&[]),
+                )];
+
+                // other applications get the text representation, if there is one
+                if let Some(text) = text.borrow().as_ref() {
+                    providers.push(gdk4::ContentProvider::for_value(
+                        &text.to_value(),
+                    ));
+                }
+
+                Some(gdk4::ContentProvider::new_union(
+                    &providers,
+                ))
+            }
+        });
+
+        // the dragged widget itself follows the pointer
+        source.connect_drag_begin({
+            let widget = widget.downgrade();
+            let payload = payload.clone();
+            let dragged = platform.drag_payload.clone();
+
+            move |source, _| {
+                dragged.set(payload.get());
+
+                if let Some(widget) = widget.upgrade() {
+                    let paintable = gtk4::WidgetPaintable::new(Some(&widget));
+                    source.set_icon(Some(&paintable.current_image()), 0, 0);
+                }
+            }
+        });
+
+        source.connect_drag_end({
+            let dragged = platform.drag_payload.clone();
+            move |_, _, _| dragged.set(None)
+        });
+
+        widget.add_controller(source.clone());
+
+        Self {
+            widget,
+            source,
+            text,
+            payload,
+            on_drag: Vec::new(),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width as i32, height as i32);
+        (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.source
+            .set_propagation_phase(propagation_phase(disabled));
+    }
+
+    fn set_text(&mut self, text: Option<String>) {
+        self.text.replace(text);
+    }
+
+    fn set_payload_type(&mut self, payload: TypeId) {
+        self.payload.set(Some(payload));
+    }
+
+    fn set_on_drag(&mut self, on_drag: impl Fn(bool) + 'static) {
+        for handler in self.on_drag.drain(..) {
+            self.source.disconnect(handler);
+        }
+
+        let on_drag = Rc::new(on_drag);
+
+        let begin = self.source.connect_drag_begin({
+            let on_drag = on_drag.clone();
+            move |_, _| on_drag(true)
+        });
+
+        let end = self.source.connect_drag_end({
+            let on_drag = on_drag.clone();
+            move |_, _, _| on_drag(false)
+        });
+
+        self.on_drag = vec![begin, end];
+    }
+}
+
+impl HasDropTarget for Platform {
+    type DropTarget = DropTarget;
+}
+
+pub struct DropTarget {
+    widget:  GroupWidget,
+    target:  gtk4::DropTargetAsync,
+    accepts: Rc<Cell<DropAccepts>>,
+    dragged: Rc<Cell<Option<TypeId>>>,
+    on_drop: Vec<glib::SignalHandlerId>,
+}
+
+impl NativeWidget<Platform> for DropTarget {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeDropTarget<Platform> for DropTarget {
+    fn build(platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        let target = gtk4::DropTargetAsync::new(None, gdk4::DragAction::COPY);
+        widget.add_controller(target.clone());
+
+        Self {
+            widget,
+            target,
+            accepts: Rc::new(Cell::new(DropAccepts::default())),
+            dragged: platform.drag_payload.clone(),
+            on_drop: Vec::new(),
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width as i32, height as i32);
+        (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.target
+            .set_propagation_phase(propagation_phase(disabled));
+    }
+
+    fn set_accepts(&mut self, accepts: DropAccepts) {
+        let mut formats = gdk4::ContentFormatsBuilder::new();
+
+        if accepts.app.is_some() {
+            formats = formats.add_mime_type(APP_MIME_TYPE);
+        }
+
+        if accepts.files {
+            formats = formats.add_type(gdk4::FileList::static_type());
+        }
+
+        if accepts.text {
+            formats = formats.add_type(String::static_type());
+        }
+
+        self.target.set_formats(Some(&formats.build()));
+        self.accepts.set(accepts);
+    }
+
+    fn set_on_drop(&mut self, on_drop: impl Fn(DropEvent) + 'static) {
+        for handler in self.on_drop.drain(..) {
+            self.target.disconnect(handler);
+        }
+
+        let on_drop = Rc::new(on_drop);
+
+        let enter = self.target.connect_drag_enter({
+            let on_drop = on_drop.clone();
+            let accepts = self.accepts.clone();
+            let dragged = self.dragged.clone();
+
+            move |_, drop, x, y| match drop_kind(drop, accepts.get(), dragged.get()) {
+                Some(kind) => {
+                    on_drop(DropEvent::Entered(kind, point(x, y)));
+                    gdk4::DragAction::COPY
+                }
+
+                None => gdk4::DragAction::empty(),
+            }
+        });
+
+        let motion = self.target.connect_drag_motion({
+            let on_drop = on_drop.clone();
+            let accepts = self.accepts.clone();
+            let dragged = self.dragged.clone();
+
+            move |_, drop, x, y| match drop_kind(drop, accepts.get(), dragged.get()) {
+                Some(_) => {
+                    on_drop(DropEvent::Moved(point(x, y)));
+                    gdk4::DragAction::COPY
+                }
+
+                None => gdk4::DragAction::empty(),
+            }
+        });
+
+        let leave = self.target.connect_drag_leave({
+            let on_drop = on_drop.clone();
+            move |_, _| on_drop(DropEvent::Left)
+        });
+
+        let dropped = self.target.connect_drop({
+            let on_drop = on_drop.clone();
+            let accepts = self.accepts.clone();
+            let dragged = self.dragged.clone();
+
+            move |_, drop, x, y| {
+                let Some(kind) = drop_kind(drop, accepts.get(), dragged.get()) else {
+                    return false;
+                };
+
+                let position = point(x, y);
+
+                if let DropKind::App = kind {
+                    on_drop(DropEvent::Dropped(
+                        DropData::App,
+                        position,
+                    ));
+                    drop.finish(gdk4::DragAction::COPY);
+                    return true;
+                }
+
+                let value_type = match kind {
+                    DropKind::Files => gdk4::FileList::static_type(),
+                    _ => String::static_type(),
+                };
+
+                // external data has to be read from the other application first
+                drop.read_value_async(
+                    value_type,
+                    glib::Priority::DEFAULT,
+                    None::<&gio::Cancellable>,
+                    {
+                        let on_drop = on_drop.clone();
+                        let drop = drop.clone();
+
+                        move |result| match result.ok().and_then(|value| drop_data(kind, value)) {
+                            Some(data) => {
+                                on_drop(DropEvent::Dropped(data, position));
+                                drop.finish(gdk4::DragAction::COPY);
+                            }
+
+                            None => {
+                                on_drop(DropEvent::Left);
+                                drop.finish(gdk4::DragAction::empty());
+                            }
+                        }
+                    },
+                );
+
+                true
+            }
+        });
+
+        self.on_drop = vec![enter, motion, leave, dropped];
+    }
+}
+
+fn drop_kind(drop: &gdk4::Drop, accepts: DropAccepts, dragged: Option<TypeId>) -> Option<DropKind> {
+    let formats = drop.formats();
+
+    // in-app drags are only taken as such when the payload is of the type of the target,
+    // otherwise only their text representation can be dropped
+    if accepts.app.is_some() && accepts.app == dragged && formats.contain_mime_type(APP_MIME_TYPE) {
+        return Some(DropKind::App);
+    }
+
+    if accepts.files && formats.contain_type(gdk4::FileList::static_type()) {
+        return Some(DropKind::Files);
+    }
+
+    if accepts.text && formats.contain_type(String::static_type()) {
+        return Some(DropKind::Text);
+    }
+
+    None
+}
+
+fn drop_data(kind: DropKind, value: glib::Value) -> Option<DropData> {
+    match kind {
+        DropKind::App => Some(DropData::App),
+
+        DropKind::Files => {
+            let files = value.get::<gdk4::FileList>().ok()?;
+
+            let files = (files.files().into_iter())
+                .map(|file| DroppedFile {
+                    uri:  file.uri().into(),
+                    path: file.path(),
+                })
+                .collect();
+
+            Some(DropData::Files(files))
+        }
+
+        DropKind::Text => value.get::<String>().ok().map(DropData::Text),
+    }
+}
+
+fn point(x: f64, y: f64) -> Point<f32> {
+    Point {
+        x: x as f32,
+        y: y as f32,
+    }
+}
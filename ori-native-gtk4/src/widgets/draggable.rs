@@ -0,0 +1,201 @@
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use gtk4::prelude::{EventControllerExt, GestureDragExt, WidgetExt};
+use ori_native_core::{
+    NativeWidget, Point,
+    native::{DragEvent, DragPhase, HasDraggable, NativeDraggable},
+};
+
+use crate::{
+    Platform,
+    widgets::{group::GroupWidget, key::propagation_phase, pressable::modifiers},
+};
+
+impl HasDraggable for Platform {
+    type Draggable = Draggable;
+}
+
+pub struct Draggable {
+    widget:   GroupWidget,
+    disabled: bool,
+    drag:     Option<gtk4::GestureDrag>,
+}
+
+impl NativeWidget<Platform> for Draggable {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeDraggable<Platform> for Draggable {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        Self {
+            widget,
+            disabled: false,
+            drag: None,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width as i32, height as i32);
+        (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+
+        if let Some(ref drag) = self.drag {
+            drag.set_propagation_phase(propagation_phase(disabled));
+        }
+    }
+
+    fn set_on_drag(&mut self, on_drag: impl Fn(DragEvent) + 'static) {
+        if let Some(drag) = self.drag.take() {
+            self.widget.remove_controller(&drag);
+        }
+
+        let on_drag = Rc::new(on_drag);
+        let samples = Rc::new(RefCell::new(VecDeque::new()));
+
+        let drag = gtk4::GestureDrag::new();
+        drag.set_propagation_phase(propagation_phase(self.disabled));
+        drag.connect_drag_begin({
+            let on_drag = on_drag.clone();
+            let samples = samples.clone();
+
+            move |gesture, x, y| {
+                samples.borrow_mut().clear();
+                sample(&samples, gesture, 0.0, 0.0);
+
+                on_drag(drag_event(
+                    gesture,
+                    DragPhase::Began,
+                    Point { x, y },
+                    Point { x: 0.0, y: 0.0 },
+                    &samples,
+                ));
+            }
+        });
+
+        drag.connect_drag_update({
+            let on_drag = on_drag.clone();
+            let samples = samples.clone();
+
+            move |gesture, x, y| {
+                sample(&samples, gesture, x, y);
+
+                on_drag(drag_event(
+                    gesture,
+                    DragPhase::Moved,
+                    start_point(gesture),
+                    Point { x, y },
+                    &samples,
+                ));
+            }
+        });
+
+        drag.connect_drag_end({
+            let on_drag = on_drag.clone();
+            let samples = samples.clone();
+
+            move |gesture, x, y| {
+                sample(&samples, gesture, x, y);
+
+                on_drag(drag_event(
+                    gesture,
+                    DragPhase::Ended,
+                    start_point(gesture),
+                    Point { x, y },
+                    &samples,
+                ));
+            }
+        });
+
+        drag.connect_cancel({
+            let on_drag = on_drag.clone();
+
+            move |gesture, _| {
+                let (x, y) = gesture.offset().unwrap_or_default();
+
+                on_drag(DragEvent {
+                    phase:     DragPhase::Cancelled,
+                    start:     to_point(start_point(gesture)),
+                    offset:    to_point(Point { x, y }),
+                    velocity:  Point { x: 0.0, y: 0.0 },
+                    modifiers: modifiers(gesture.current_event_state()),
+                });
+            }
+        });
+
+        self.drag = Some(drag.clone());
+        self.widget.add_controller(drag);
+    }
+}
+
+// only the last moments of the drag count towards the release velocity
+const VELOCITY_WINDOW: u32 = 100;
+
+type Samples = Rc<RefCell<VecDeque<(u32, f64, f64)>>>;
+
+fn sample(samples: &Samples, gesture: &gtk4::GestureDrag, x: f64, y: f64) {
+    let time = gesture.current_event_time();
+    let mut samples = samples.borrow_mut();
+
+    samples.push_back((time, x, y));
+
+    while let Some(&(first, _, _)) = samples.front()
+        && time.wrapping_sub(first) > VELOCITY_WINDOW
+    {
+        samples.pop_front();
+    }
+}
+
+fn velocity(samples: &Samples) -> Point<f32> {
+    let samples = samples.borrow();
+
+    match (samples.front(), samples.back()) {
+        (Some(&(t0, x0, y0)), Some(&(t1, x1, y1))) if t1 != t0 => {
+            let dt = t1.wrapping_sub(t0) as f64 / 1000.0;
+
+            Point {
+                x: ((x1 - x0) / dt) as f32,
+                y: ((y1 - y0) / dt) as f32,
+            }
+        }
+
+        _ => Point { x: 0.0, y: 0.0 },
+    }
+}
+
+fn start_point(gesture: &gtk4::GestureDrag) -> Point<f64> {
+    let (x, y) = gesture.start_point().unwrap_or_default();
+    Point { x, y }
+}
+
+fn to_point(point: Point<f64>) -> Point<f32> {
+    Point {
+        x: point.x as f32,
+        y: point.y as f32,
+    }
+}
+
+fn drag_event(
+    gesture: &gtk4::GestureDrag,
+    phase: DragPhase,
+    start: Point<f64>,
+    offset: Point<f64>,
+    samples: &Samples,
+) -> DragEvent {
+    DragEvent {
+        phase,
+        start: to_point(start),
+        offset: to_point(offset),
+        velocity: velocity(samples),
+        modifiers: modifiers(gesture.current_event_state()),
+    }
+}
@@ -0,0 +1,152 @@
+use gio::prelude::{CancellableExt, FileExt, ListModelExt};
+use glib::object::CastNone;
+use ori_native_core::{
+    NativeWidget,
+    native::{FileDialogMode, FileDialogOptions, HasFileDialog, NativeFileDialog, SelectedFile},
+};
+
+use crate::{
+    Platform,
+    widgets::{dialog::with_parent, group::GroupWidget},
+};
+
+impl HasFileDialog for Platform {
+    type FileDialog = FileDialog;
+}
+
+pub struct FileDialog {
+    placeholder: GroupWidget,
+    cancellable: Option<gio::Cancellable>,
+}
+
+impl NativeWidget<Platform> for FileDialog {
+    fn widget(&self) -> &gtk4::Widget {
+        self.placeholder.as_ref()
+    }
+}
+
+impl NativeFileDialog<Platform> for FileDialog {
+    fn build(_platform: &mut Platform) -> Self {
+        Self {
+            placeholder: GroupWidget::new(),
+            cancellable: None,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {
+        if let Some(cancellable) = self.cancellable {
+            cancellable.cancel();
+        }
+    }
+
+    fn show(
+        &mut self,
+        options: &FileDialogOptions,
+        on_result: impl Fn(Option<Vec<SelectedFile>>) + 'static,
+    ) {
+        if let Some(cancellable) = self.cancellable.take() {
+            cancellable.cancel();
+        }
+
+        let cancellable = gio::Cancellable::new();
+        self.cancellable = Some(cancellable.clone());
+
+        // without a portal, gtk falls back to its own file chooser
+        let dialog = file_dialog(options);
+        let mode = options.mode;
+
+        let on_result = move |files: Option<Vec<SelectedFile>>| {
+            // a newer request or teardown cancelled this one, so drop the result
+            if !cancellable.is_cancelled() {
+                on_result(files);
+            }
+        };
+
+        let cancellable = self.cancellable.clone();
+
+        with_parent(&self.placeholder, move |parent| {
+            let parent = parent.as_ref();
+            let cancellable = cancellable.as_ref();
+
+            match mode {
+                FileDialogMode::Open => dialog.open(parent, cancellable, move |result| {
+                    on_result(file(result));
+                }),
+
+                FileDialogMode::OpenMultiple => {
+                    dialog.open_multiple(parent, cancellable, move |result| {
+                        on_result(result.ok().map(|files| selected_files(&files)));
+                    })
+                }
+
+                FileDialogMode::Save => dialog.save(parent, cancellable, move |result| {
+                    on_result(file(result));
+                }),
+
+                FileDialogMode::Folder => {
+                    dialog.select_folder(parent, cancellable, move |result| {
+                        on_result(file(result));
+                    })
+                }
+            }
+        });
+    }
+}
+
+fn file_dialog(options: &FileDialogOptions) -> gtk4::FileDialog {
+    let dialog = gtk4::FileDialog::new();
+    dialog.set_modal(true);
+
+    if let Some(ref title) = options.title {
+        dialog.set_title(title);
+    }
+
+    if !options.filters.is_empty() {
+        let filters = gio::ListStore::new::<gtk4::FileFilter>();
+
+        for filter in &options.filters {
+            let file_filter = gtk4::FileFilter::new();
+            file_filter.set_name(Some(&filter.name));
+
+            for pattern in &filter.patterns {
+                file_filter.add_pattern(pattern);
+            }
+
+            for mime_type in &filter.mime_types {
+                file_filter.add_mime_type(mime_type);
+            }
+
+            filters.append(&file_filter);
+        }
+
+        dialog.set_filters(Some(&filters));
+    }
+
+    if let Some(ref directory) = options.directory {
+        dialog.set_initial_folder(Some(&gio::File::for_path(directory)));
+    }
+
+    if let Some(ref name) = options.name {
+        dialog.set_initial_name(Some(name));
+    }
+
+    dialog
+}
+
+fn file(result: Result<gio::File, glib::Error>) -> Option<Vec<SelectedFile>> {
+    result.ok().map(|file| vec![selected_file(&file)])
+}
+
+fn selected_files(files: &gio::ListModel) -> Vec<SelectedFile> {
+    (0..files.n_items())
+        .filter_map(|index| files.item(index).and_downcast::<gio::File>())
+        .map(|file| selected_file(&file))
+        .collect()
+}
+
+fn selected_file(file: &gio::File) -> SelectedFile {
+    SelectedFile {
+        uri:  file.uri().into(),
+        path: file.path(),
+    }
+}
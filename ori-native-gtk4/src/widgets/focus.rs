@@ -0,0 +1,87 @@
+use std::rc::Rc;
+
+use glib::object::ObjectExt;
+use gtk4::prelude::WidgetExt;
+use ori_native_core::{
+    NativeWidget,
+    native::{HasFocusTarget, NativeFocusTarget},
+};
+
+use crate::{Platform, widgets::group::GroupWidget};
+
+impl HasFocusTarget for Platform {
+    type FocusTarget = FocusTarget;
+}
+
+pub struct FocusTarget {
+    widget: GroupWidget,
+    focus:  Option<gtk4::EventControllerFocus>,
+}
+
+impl NativeWidget<Platform> for FocusTarget {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeFocusTarget<Platform> for FocusTarget {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        Self {
+            widget,
+            focus: None,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width as i32, height as i32);
+        (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
+    }
+
+    fn set_tab_index(&mut self, tab_index: i32) {
+        self.widget.set_tab_index(tab_index);
+    }
+
+    fn set_trap(&mut self, trap: bool) {
+        self.widget.set_trap_focus(trap);
+    }
+
+    fn set_on_focus(&mut self, on_focus: impl Fn(bool) + 'static) {
+        if let Some(focus) = self.focus.take() {
+            self.widget.remove_controller(&focus);
+        }
+
+        let on_focus = Rc::new(on_focus);
+
+        let controller = gtk4::EventControllerFocus::new();
+        controller.connect_enter({
+            let on_focus = on_focus.clone();
+            move |_| on_focus(true)
+        });
+
+        controller.connect_leave({
+            let on_focus = on_focus.clone();
+            move |_| on_focus(false)
+        });
+
+        self.focus = Some(controller.clone());
+        self.widget.add_controller(controller);
+    }
+
+    fn focus(&mut self) {
+        let widget = self.widget.downgrade();
+
+        // the target may have just been built, and can only take focus once it's in a window
+        glib::idle_add_local_once(move || {
+            if let Some(widget) = widget.upgrade()
+                && !widget.grab_focus()
+            {
+                widget.child_focus(gtk4::DirectionType::TabForward);
+            }
+        });
+    }
+}
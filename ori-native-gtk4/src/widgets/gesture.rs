@@ -0,0 +1,194 @@
+use std::rc::Rc;
+
+use gtk4::prelude::{EventControllerExt, GestureExt, WidgetExt};
+use ori_native_core::{
+    NativeWidget, Point,
+    native::{GesturePhase, HasGesture, NativeGesture, RotateEvent, ZoomEvent},
+};
+
+use crate::{
+    Platform,
+    widgets::{group::GroupWidget, key::propagation_phase},
+};
+
+impl HasGesture for Platform {
+    type Gesture = Gesture;
+}
+
+pub struct Gesture {
+    widget:   GroupWidget,
+    disabled: bool,
+    zoom:     Option<gtk4::GestureZoom>,
+    rotate:   Option<gtk4::GestureRotate>,
+    swipe:    Option<gtk4::GestureSwipe>,
+}
+
+impl NativeWidget<Platform> for Gesture {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeGesture<Platform> for Gesture {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        Self {
+            widget,
+            disabled: false,
+            zoom: None,
+            rotate: None,
+            swipe: None,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width as i32, height as i32);
+        (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+
+        let phase = propagation_phase(disabled);
+
+        if let Some(ref zoom) = self.zoom {
+            zoom.set_propagation_phase(phase);
+        }
+
+        if let Some(ref rotate) = self.rotate {
+            rotate.set_propagation_phase(phase);
+        }
+
+        if let Some(ref swipe) = self.swipe {
+            swipe.set_propagation_phase(phase);
+        }
+    }
+
+    fn set_on_zoom(&mut self, on_zoom: impl Fn(ZoomEvent) + 'static) {
+        if let Some(zoom) = self.zoom.take() {
+            self.widget.remove_controller(&zoom);
+        }
+
+        let on_zoom = Rc::new(on_zoom);
+
+        let zoom = gtk4::GestureZoom::new();
+        zoom.set_propagation_phase(propagation_phase(self.disabled));
+        connect_phases(&zoom, {
+            let on_zoom = on_zoom.clone();
+
+            move |gesture, phase| {
+                on_zoom(ZoomEvent {
+                    phase,
+                    scale: gesture.scale_delta() as f32,
+                    focal: focal(gesture),
+                });
+            }
+        });
+
+        zoom.connect_scale_changed({
+            let on_zoom = on_zoom.clone();
+
+            move |gesture, scale| {
+                on_zoom(ZoomEvent {
+                    phase: GesturePhase::Changed,
+                    scale: scale as f32,
+                    focal: focal(gesture),
+                });
+            }
+        });
+
+        self.zoom = Some(zoom.clone());
+        self.widget.add_controller(zoom);
+    }
+
+    fn set_on_rotate(&mut self, on_rotate: impl Fn(RotateEvent) + 'static) {
+        if let Some(rotate) = self.rotate.take() {
+            self.widget.remove_controller(&rotate);
+        }
+
+        let on_rotate = Rc::new(on_rotate);
+
+        let rotate = gtk4::GestureRotate::new();
+        rotate.set_propagation_phase(propagation_phase(self.disabled));
+        connect_phases(&rotate, {
+            let on_rotate = on_rotate.clone();
+
+            move |gesture, phase| {
+                on_rotate(RotateEvent {
+                    phase,
+                    angle: gesture.angle_delta() as f32,
+                    focal: focal(gesture),
+                });
+            }
+        });
+
+        // the delta is relative to the angle between the fingers when the gesture began
+        rotate.connect_angle_changed({
+            let on_rotate = on_rotate.clone();
+
+            move |gesture, _, delta| {
+                on_rotate(RotateEvent {
+                    phase: GesturePhase::Changed,
+                    angle: delta as f32,
+                    focal: focal(gesture),
+                });
+            }
+        });
+
+        self.rotate = Some(rotate.clone());
+        self.widget.add_controller(rotate);
+    }
+
+    fn set_on_swipe(&mut self, on_swipe: impl Fn(Point<f32>) + 'static) {
+        if let Some(swipe) = self.swipe.take() {
+            self.widget.remove_controller(&swipe);
+        }
+
+        let swipe = gtk4::GestureSwipe::new();
+        swipe.set_propagation_phase(propagation_phase(self.disabled));
+        swipe.connect_swipe(move |_, x, y| {
+            on_swipe(Point {
+                x: x as f32,
+                y: y as f32,
+            });
+        });
+
+        self.swipe = Some(swipe.clone());
+        self.widget.add_controller(swipe);
+    }
+}
+
+fn connect_phases<G>(gesture: &G, on_phase: impl Fn(&G, GesturePhase) + 'static)
+where
+    G: glib::object::IsA<gtk4::Gesture>,
+{
+    let on_phase = Rc::new(on_phase);
+
+    gesture.connect_begin({
+        let on_phase = on_phase.clone();
+        move |gesture, _| on_phase(gesture, GesturePhase::Began)
+    });
+
+    gesture.connect_end({
+        let on_phase = on_phase.clone();
+        move |gesture, _| on_phase(gesture, GesturePhase::Ended)
+    });
+
+    gesture.connect_cancel({
+        let on_phase = on_phase.clone();
+        move |gesture, _| on_phase(gesture, GesturePhase::Cancelled)
+    });
+}
+
+fn focal(gesture: &impl glib::object::IsA<gtk4::Gesture>) -> Point<f32> {
+    let (x, y) = gesture.bounding_box_center().unwrap_or_default();
+
+    Point {
+        x: x as f32,
+        y: y as f32,
+    }
+}
@@ -50,6 +50,10 @@ impl NativeGroup<Platform> for Group {
         self.group.swap_children(index_a, index_b);
     }
 
+    fn set_raised(&mut self, raised: bool) {
+        self.group.set_raised(raised);
+    }
+
     fn set_size(&mut self, width: f32, height: f32) {
         self.group.set_size(
             width.round() as i32,
@@ -140,6 +144,37 @@ impl GroupWidget {
         }
     }
 
+    pub fn set_tab_index(&self, tab_index: i32) {
+        self.imp().tab_index.set(tab_index);
+    }
+
+    pub fn set_trap_focus(&self, trap_focus: bool) {
+        self.imp().trap_focus.set(trap_focus);
+    }
+
+    // only a parent group draws its raised children last, the order of the widgets themselves,
+    // which focus and accessibility follow, is left alone
+    pub fn set_raised(&self, raised: bool) {
+        if self.imp().raised.get() != raised {
+            self.imp().raised.set(raised);
+
+            if let Some(parent) = self.parent() {
+                parent.queue_draw();
+            }
+        }
+    }
+
+    // popovers are children of the widget they point at, but aren't part of the layout
+    pub fn add_popover(&self, popover: &gtk4::Popover) {
+        popover.set_parent(self);
+        self.imp().popovers.borrow_mut().push(popover.clone());
+    }
+
+    pub fn remove_popover(&self, popover: &gtk4::Popover) {
+        self.imp().popovers.borrow_mut().retain(|p| p != popover);
+        popover.unparent();
+    }
+
     pub fn insert_child(&self, index: usize, child: &gtk4::Widget) {
         let mut children = self.imp().children.borrow_mut();
 
@@ -188,12 +223,15 @@ impl GroupWidget {
 mod imp {
     use std::cell::{Cell, RefCell};
 
-    use glib::subclass::{
-        object::ObjectImpl,
-        types::{ObjectSubclass, ObjectSubclassExt},
+    use glib::{
+        object::Cast,
+        subclass::{
+            object::ObjectImpl,
+            types::{ObjectSubclass, ObjectSubclassExt, ObjectSubclassIsExt},
+        },
     };
     use gtk4::{
-        prelude::{SnapshotExt, SnapshotExtManual, WidgetExt},
+        prelude::{PopoverExt, RootExt, SnapshotExt, SnapshotExtManual, WidgetExt},
         subclass::widget::{WidgetClassExt, WidgetImpl, WidgetImplExt},
     };
 
@@ -207,6 +245,12 @@ mod imp {
         pub(super) border_color:     Cell<gdk4::RGBA>,
         pub(super) corner_radii:     Cell<[f32; 4]>,
         pub(super) border_width:     Cell<[f32; 4]>,
+
+        pub(super) tab_index:  Cell<i32>,
+        pub(super) trap_focus: Cell<bool>,
+        pub(super) raised:     Cell<bool>,
+
+        pub(super) popovers: RefCell<Vec<gtk4::Popover>>,
     }
 
     pub(super) struct Child {
@@ -229,6 +273,12 @@ mod imp {
                 border_color:     Cell::new(gdk4::RGBA::TRANSPARENT),
                 corner_radii:     Cell::new([0.0; 4]),
                 border_width:     Cell::new([0.0; 4]),
+
+                tab_index:  Cell::new(0),
+                trap_focus: Cell::new(false),
+                raised:     Cell::new(false),
+
+                popovers: RefCell::default(),
             }
         }
     }
@@ -249,10 +299,88 @@ mod imp {
             for child in self.children.borrow().iter() {
                 child.widget.unparent();
             }
+
+            for popover in self.popovers.borrow().iter() {
+                popover.unparent();
+            }
         }
     }
 
     impl WidgetImpl for GroupWidget {
+        fn focus(&self, direction: gtk4::DirectionType) -> bool {
+            let forward = match direction {
+                gtk4::DirectionType::TabForward => true,
+                gtk4::DirectionType::TabBackward => false,
+                _ => return self.parent_focus(direction),
+            };
+
+            let widget = self.obj();
+            let focus_child = widget.focus_child();
+
+            // a negative tab index takes the group out of the tab order, unless focus is
+            // already inside of it
+            if self.tab_index.get() < 0 && focus_child.is_none() && !widget.has_focus() {
+                return false;
+            }
+
+            // tab order follows the tab index of the children, positive indices first, then the
+            // children without one in the order they were inserted
+            let mut children: Vec<_> = (self.children.borrow().iter())
+                .map(|child| child.widget.clone())
+                .collect();
+
+            children.sort_by_key(|child| match tab_index(child) {
+                index if index > 0 => (0, index),
+                _ => (1, 0),
+            });
+
+            if !forward {
+                children.reverse();
+            }
+
+            if widget.has_focus() {
+                if !forward {
+                    return false;
+                }
+            } else if forward && focus_child.is_none() && widget.is_focusable() {
+                widget.grab_focus();
+                return true;
+            }
+
+            let start = match focus_child {
+                Some(ref focus_child) => match children.iter().position(|c| c == focus_child) {
+                    Some(index) if children[index].child_focus(direction) => return true,
+                    Some(index) => index + 1,
+                    None => 0,
+                },
+
+                None => 0,
+            };
+
+            for child in &children[start..] {
+                if child.is_visible() && child.is_sensitive() && child.child_focus(direction) {
+                    return true;
+                }
+            }
+
+            if !forward && !widget.has_focus() && widget.is_focusable() {
+                widget.grab_focus();
+                return true;
+            }
+
+            // a trapping group wraps around instead of letting focus leave it, by clearing the
+            // focus and starting over from the first child
+            if self.trap_focus.get()
+                && (focus_child.is_some() || widget.has_focus())
+                && let Some(root) = widget.root()
+            {
+                root.set_focus(None::<&gtk4::Widget>);
+                return self.focus(direction);
+            }
+
+            false
+        }
+
         fn snapshot(&self, snapshot: &gtk4::Snapshot) {
             let alloc = self.obj().allocation();
             let [tl, tr, br, bl] = self.corner_radii.get();
@@ -283,7 +411,17 @@ mod imp {
                 &[self.border_color.get(); 4],
             );
 
-            self.parent_snapshot(snapshot);
+            let widget = self.obj();
+            let children: Vec<_> = (self.children.borrow().iter())
+                .map(|child| child.widget.clone())
+                .collect();
+
+            // raised children are drawn over the others, in the order they were inserted
+            for raised in [false, true] {
+                for child in children.iter().filter(|child| is_raised(child) == raised) {
+                    widget.snapshot_child(child, snapshot);
+                }
+            }
 
             snapshot.pop();
         }
@@ -302,6 +440,10 @@ mod imp {
                     -1,
                 );
             }
+
+            for popover in self.popovers.borrow().iter() {
+                popover.present();
+            }
         }
 
         fn measure(&self, orientation: gtk4::Orientation, for_size: i32) -> (i32, i32, i32, i32) {
@@ -324,4 +466,18 @@ mod imp {
             }
         }
     }
+
+    fn is_raised(widget: &gtk4::Widget) -> bool {
+        match widget.downcast_ref::<super::GroupWidget>() {
+            Some(group) => group.imp().raised.get(),
+            None => false,
+        }
+    }
+
+    fn tab_index(widget: &gtk4::Widget) -> i32 {
+        match widget.downcast_ref::<super::GroupWidget>() {
+            Some(group) => group.imp().tab_index.get(),
+            None => 0,
+        }
+    }
 }
@@ -1,11 +1,15 @@
-use std::{borrow::Cow, io};
+use std::{borrow::Cow, io, rc::Rc, sync::Arc};
 
 use gdk4::prelude::PaintableExt;
-use glib::subclass::types::ObjectSubclassIsExt;
+use glib::{
+    object::{Cast, ObjectExt},
+    subclass::types::ObjectSubclassIsExt,
+};
+use gtk4::prelude::WidgetExt;
 use librsvg::prelude::HandleExt;
 use ori_native_core::{
     Color, LayoutLeaf, NativeWidget,
-    native::{HasImage, NativeImage},
+    native::{HasImage, NativeImage, SvgOptions},
 };
 
 use crate::Platform;
@@ -15,9 +19,42 @@ impl HasImage for Platform {
 }
 
 pub struct Image {
-    image: gtk4::Picture,
-    svg:   Option<Svg>,
-    tint:  Option<Color>,
+    image:       gtk4::Picture,
+    svg:         Option<Svg>,
+    raster:      Option<Raster>,
+    icon:        Option<IconSource>,
+    tint:        Option<Color>,
+    svg_options: SvgOptions,
+}
+
+struct IconSource {
+    theme:         gtk4::IconTheme,
+    theme_changed: glib::SignalHandlerId,
+    scale_changed: glib::SignalHandlerId,
+}
+
+impl Image {
+    fn raster(&mut self) -> Raster {
+        if let Some(ref raster) = self.raster {
+            return raster.clone();
+        }
+
+        let raster = Raster::new();
+        raster.set_tint(self.tint);
+        self.image.set_paintable(Some(&raster));
+
+        self.svg = None;
+        self.raster = Some(raster.clone());
+
+        raster
+    }
+
+    fn clear_icon(&mut self) {
+        if let Some(icon) = self.icon.take() {
+            icon.theme.disconnect(icon.theme_changed);
+            self.image.disconnect(icon.scale_changed);
+        }
+    }
 }
 
 impl NativeWidget<Platform> for Image {
@@ -35,30 +72,162 @@ impl NativeImage<Platform> for Image {
         Self {
             image,
             svg: None,
+            raster: None,
+            icon: None,
             tint: None,
+            svg_options: SvgOptions::default(),
         }
     }
 
-    fn teardown(self, _plaform: &mut Platform) {}
+    fn teardown(mut self, _plaform: &mut Platform) {
+        self.clear_icon();
+    }
 
     fn load_data(
         &mut self,
         _plaform: &mut Platform,
         data: Cow<'static, [u8]>,
     ) -> Result<impl LayoutLeaf<Platform>, Self::Error> {
+        self.clear_icon();
+
         let svg = Svg::new(&data)?;
         svg.set_tint(self.tint);
+        svg.set_options(&self.svg_options);
         self.image.set_paintable(Some(&svg));
 
+        self.svg = Some(svg.clone());
+        self.raster = None;
+
         Ok(Layout { svg })
     }
 
+    fn load_rgba(
+        &mut self,
+        _plaform: &mut Platform,
+        width: u32,
+        height: u32,
+        pixels: Arc<[u8]>,
+    ) -> Result<impl LayoutLeaf<Platform>, Self::Error> {
+        let stride = width as usize * 4;
+
+        if width == 0 || height == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "image must not be empty",
+            ));
+        }
+
+        if pixels.len() != stride * height as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "pixel buffer length must be width * height * 4",
+            ));
+        }
+
+        let texture = gdk4::MemoryTexture::new(
+            width as i32,
+            height as i32,
+            gdk4::MemoryFormat::R8g8b8a8,
+            &glib::Bytes::from_owned(pixels),
+            stride,
+        );
+
+        self.clear_icon();
+        self.raster().set_paintable(texture.upcast());
+
+        Ok(RasterLayout { width, height })
+    }
+
+    fn load_icon(
+        &mut self,
+        platform: &mut Platform,
+        name: &str,
+        size: u32,
+        scale: Option<u32>,
+    ) -> Result<impl LayoutLeaf<Platform>, Self::Error> {
+        self.clear_icon();
+
+        let theme = gtk4::IconTheme::for_display(&platform.display);
+
+        let lookup = Rc::new({
+            let name = name.to_owned();
+
+            move |theme: &gtk4::IconTheme, image: &gtk4::Picture| {
+                let scale = scale.map_or(image.scale_factor(), |scale| {
+                    scale as i32
+                });
+
+                theme.lookup_icon(
+                    &name,
+                    &[],
+                    size as i32,
+                    scale,
+                    gtk4::TextDirection::None,
+                    gtk4::IconLookupFlags::empty(),
+                )
+            }
+        });
+
+        let raster = self.raster();
+        raster.set_paintable(lookup(&theme, &self.image).upcast());
+
+        // icons are resolved again when the theme changes or the widget moves to a monitor with a
+        // different scale factor
+        let theme_changed = theme.connect_changed({
+            let lookup = lookup.clone();
+            let image = self.image.downgrade();
+            let raster = raster.downgrade();
+
+            move |theme| {
+                if let Some(image) = image.upgrade()
+                    && let Some(raster) = raster.upgrade()
+                {
+                    raster.set_paintable(lookup(theme, &image).upcast());
+                }
+            }
+        });
+
+        let scale_changed = self.image.connect_scale_factor_notify({
+            let theme = theme.clone();
+            let raster = raster.downgrade();
+
+            move |image| {
+                if let Some(raster) = raster.upgrade() {
+                    raster.set_paintable(lookup(&theme, image).upcast());
+                }
+            }
+        });
+
+        self.icon = Some(IconSource {
+            theme,
+            theme_changed,
+            scale_changed,
+        });
+
+        Ok(RasterLayout {
+            width:  size,
+            height: size,
+        })
+    }
+
     fn set_tint(&mut self, tint: Option<Color>) {
         self.tint = tint;
 
         if let Some(ref svg) = self.svg {
             svg.set_tint(tint);
         }
+
+        if let Some(ref raster) = self.raster {
+            raster.set_tint(tint);
+        }
+    }
+
+    fn set_svg_options(&mut self, options: &SvgOptions) {
+        self.svg_options = options.clone();
+
+        if let Some(ref svg) = self.svg {
+            svg.set_options(options);
+        }
     }
 }
 
@@ -82,6 +251,25 @@ impl LayoutLeaf<Platform> for Layout {
     }
 }
 
+struct RasterLayout {
+    width:  u32,
+    height: u32,
+}
+
+impl LayoutLeaf<Platform> for RasterLayout {
+    fn measure(
+        &mut self,
+        _platform: &mut Platform,
+        _known_size: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+    ) -> taffy::Size<f32> {
+        taffy::Size {
+            width:  self.width as f32,
+            height: self.height as f32,
+        }
+    }
+}
+
 glib::wrapper! {
     struct Svg(ObjectSubclass<imp::Svg>)
         @implements
@@ -106,24 +294,108 @@ impl Svg {
         }
     }
 
+    fn set_options(&self, options: &SvgOptions) {
+        let mut stylesheet = options.stylesheet.as_deref().unwrap_or_default().to_owned();
+
+        // librsvg resolves `currentColor` from the inherited `color` property, user stylesheets
+        // take precedence over the presentation attributes of the document
+        if let Some(color) = options.current_color {
+            stylesheet += &format!(
+                "\nsvg {{ color: rgba({}, {}, {}, {}); }}",
+                color.r * 255.0,
+                color.g * 255.0,
+                color.b * 255.0,
+                color.a,
+            );
+        }
+
+        if let Err(err) = self
+            .imp()
+            .handle
+            .borrow()
+            .set_stylesheet(stylesheet.as_bytes())
+        {
+            tracing::warn!("failed to set svg stylesheet: {err}");
+        }
+
+        if self.imp().view_box.replace(options.view_box) != options.view_box {
+            self.invalidate_size();
+        }
+
+        self.invalidate_contents();
+    }
+
     fn intrinsic_size(&self) -> Option<(f64, f64)> {
+        if let Some(view_box) = self.imp().view_box.get() {
+            return Some((
+                view_box.width as f64,
+                view_box.height as f64,
+            ));
+        }
+
+        self.document_size()
+    }
+
+    fn document_size(&self) -> Option<(f64, f64)> {
         self.imp().handle.borrow().intrinsic_size_in_pixels()
     }
 }
 
+glib::wrapper! {
+    struct Raster(ObjectSubclass<imp::Raster>)
+        @implements
+            gdk4::Paintable;
+}
+
+impl Raster {
+    fn new() -> Self {
+        glib::Object::builder().build()
+    }
+
+    fn set_paintable(&self, paintable: gdk4::Paintable) {
+        let resized = match *self.imp().paintable.borrow() {
+            Some(ref current) => {
+                current.intrinsic_width() != paintable.intrinsic_width()
+                    || current.intrinsic_height() != paintable.intrinsic_height()
+            }
+
+            None => true,
+        };
+
+        self.imp().paintable.replace(Some(paintable));
+
+        if resized {
+            self.invalidate_size();
+        }
+
+        self.invalidate_contents();
+    }
+
+    fn set_tint(&self, tint: Option<Color>) {
+        if self.imp().tint.replace(tint) != tint {
+            self.invalidate_contents();
+        }
+    }
+}
+
 mod imp {
     use std::cell::{Cell, RefCell};
 
-    use gdk4::subclass::prelude::PaintableImpl;
-    use glib::subclass::{object::ObjectImpl, types::ObjectSubclass};
+    use gdk4::{prelude::PaintableExt, subclass::prelude::PaintableImpl};
+    use glib::subclass::types::ObjectSubclassExt;
+    use glib::{
+        object::Cast,
+        subclass::{object::ObjectImpl, types::ObjectSubclass},
+    };
     use gtk4::prelude::SnapshotExt;
     use librsvg::prelude::HandleExt;
-    use ori_native_core::Color;
+    use ori_native_core::{Color, native::ViewBox};
 
     #[derive(Default)]
     pub(super) struct Svg {
-        pub(super) handle: RefCell<librsvg::Handle>,
-        pub(super) tint:   Cell<Option<Color>>,
+        pub(super) handle:   RefCell<librsvg::Handle>,
+        pub(super) tint:     Cell<Option<Color>>,
+        pub(super) view_box: Cell<Option<ViewBox>>,
     }
 
     #[glib::object_subclass]
@@ -150,10 +422,28 @@ mod imp {
                 cr.push_group();
             }
 
-            let _ = self.handle.borrow().render_document(
-                &cr,
-                &librsvg::Rectangle::new(0.0, 0.0, width, height),
-            );
+            // when only a part of the document is shown, the whole document is scaled and offset
+            // such that the view box fills the viewport, everything else is clipped
+            let viewport = match self.view_box.get() {
+                Some(view_box) if view_box.width > 0.0 && view_box.height > 0.0 => {
+                    let (document_width, document_height) =
+                        self.obj().document_size().unwrap_or((width, height));
+
+                    let scale_x = width / view_box.width as f64;
+                    let scale_y = height / view_box.height as f64;
+
+                    librsvg::Rectangle::new(
+                        -view_box.x as f64 * scale_x,
+                        -view_box.y as f64 * scale_y,
+                        document_width * scale_x,
+                        document_height * scale_y,
+                    )
+                }
+
+                _ => librsvg::Rectangle::new(0.0, 0.0, width, height),
+            };
+
+            let _ = self.handle.borrow().render_document(&cr, &viewport);
 
             if let Some(tint) = self.tint.get()
                 && let Ok(mask) = cr.pop_group()
@@ -169,4 +459,65 @@ mod imp {
             }
         }
     }
+
+    #[derive(Default)]
+    pub(super) struct Raster {
+        pub(super) paintable: RefCell<Option<gdk4::Paintable>>,
+        pub(super) tint:      Cell<Option<Color>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Raster {
+        const NAME: &'static str = "OriRaster";
+
+        type Type = super::Raster;
+        type ParentType = glib::Object;
+        type Interfaces = (gdk4::Paintable,);
+    }
+
+    impl ObjectImpl for Raster {}
+
+    impl PaintableImpl for Raster {
+        fn intrinsic_width(&self) -> i32 {
+            (self.paintable.borrow().as_ref()).map_or(0, |paintable| {
+                paintable.intrinsic_width()
+            })
+        }
+
+        fn intrinsic_height(&self) -> i32 {
+            (self.paintable.borrow().as_ref()).map_or(0, |paintable| {
+                paintable.intrinsic_height()
+            })
+        }
+
+        fn snapshot(&self, snapshot: &gdk4::Snapshot, width: f64, height: f64) {
+            let Some(snapshot) = snapshot.downcast_ref::<gtk4::Snapshot>() else {
+                return;
+            };
+
+            let Some(ref paintable) = *self.paintable.borrow() else {
+                return;
+            };
+
+            // replace the color of every pixel with the tint, keeping the alpha
+            if let Some(tint) = self.tint.get() {
+                let matrix = graphene::Matrix::from_float([
+                    0.0, 0.0, 0.0, 0.0, //
+                    0.0, 0.0, 0.0, 0.0, //
+                    0.0, 0.0, 0.0, 0.0, //
+                    0.0, 0.0, 0.0, tint.a,
+                ]);
+
+                let offset = graphene::Vec4::new(tint.r, tint.g, tint.b, 0.0);
+
+                snapshot.push_color_matrix(&matrix, &offset);
+            }
+
+            paintable.snapshot(snapshot, width, height);
+
+            if self.tint.get().is_some() {
+                snapshot.pop();
+            }
+        }
+    }
 }
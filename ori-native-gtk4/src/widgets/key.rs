@@ -0,0 +1,333 @@
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+use glib::translate::IntoGlib;
+use gtk4::prelude::{EventControllerExt, WidgetExt};
+use ori_native_core::{
+    Key, KeyEvent, KeyPhase, Modifiers, NativeWidget, Shortcut,
+    native::{HasKeyHandler, HasShortcuts, NativeKeyHandler, NativeShortcuts, ShortcutScope},
+};
+
+use crate::{
+    Platform,
+    widgets::{group::GroupWidget, pressable::modifiers},
+};
+
+impl HasKeyHandler for Platform {
+    type KeyHandler = KeyHandler;
+}
+
+pub struct KeyHandler {
+    widget:   GroupWidget,
+    disabled: bool,
+    keys:     Option<gtk4::EventControllerKey>,
+}
+
+impl NativeWidget<Platform> for KeyHandler {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeKeyHandler<Platform> for KeyHandler {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        Self {
+            widget,
+            disabled: false,
+            keys: None,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width as i32, height as i32);
+        (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+
+        if let Some(ref keys) = self.keys {
+            keys.set_propagation_phase(propagation_phase(disabled));
+        }
+    }
+
+    fn set_focusable(&mut self, focusable: bool) {
+        self.widget.set_focusable(focusable);
+    }
+
+    fn set_on_key(&mut self, on_key: impl Fn(u64, KeyEvent) + 'static) {
+        if let Some(keys) = self.keys.take() {
+            self.widget.remove_controller(&keys);
+        }
+
+        let on_key = Rc::new(on_key);
+        let pressed = Rc::new(RefCell::new(HashSet::new()));
+
+        let keys = gtk4::EventControllerKey::new();
+        keys.set_propagation_phase(propagation_phase(self.disabled));
+
+        keys.connect_key_pressed({
+            let on_key = on_key.clone();
+            let pressed = pressed.clone();
+
+            move |controller, keyval, keycode, state| {
+                // a key that is already down is being repeated
+                let repeat = !pressed.borrow_mut().insert(keycode);
+
+                on_key(
+                    serial(controller, keycode, KeyPhase::Pressed),
+                    KeyEvent {
+                        key: key(keyval),
+                        phase: KeyPhase::Pressed,
+                        modifiers: modifiers(state),
+                        repeat,
+                    },
+                );
+
+                // the views handle the event asynchronously, so it always proceeds to gtk
+                glib::Propagation::Proceed
+            }
+        });
+
+        keys.connect_key_released({
+            let on_key = on_key.clone();
+            let pressed = pressed.clone();
+
+            move |controller, keyval, keycode, state| {
+                pressed.borrow_mut().remove(&keycode);
+
+                on_key(
+                    serial(controller, keycode, KeyPhase::Released),
+                    KeyEvent {
+                        key:       key(keyval),
+                        phase:     KeyPhase::Released,
+                        modifiers: modifiers(state),
+                        repeat:    false,
+                    },
+                );
+            }
+        });
+
+        self.keys = Some(keys.clone());
+        self.widget.add_controller(keys);
+    }
+}
+
+impl HasShortcuts for Platform {
+    type Shortcuts = Shortcuts;
+}
+
+pub struct Shortcuts {
+    widget:     GroupWidget,
+    scope:      ShortcutScope,
+    disabled:   bool,
+    controller: Option<gtk4::ShortcutController>,
+}
+
+impl NativeWidget<Platform> for Shortcuts {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeShortcuts<Platform> for Shortcuts {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        Self {
+            widget,
+            scope: ShortcutScope::Subtree,
+            disabled: false,
+            controller: None,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width as i32, height as i32);
+        (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+
+        // window wide shortcuts don't go through the widget, so the controller is turned off
+        if let Some(ref controller) = self.controller {
+            controller.set_propagation_phase(propagation_phase(disabled));
+        }
+    }
+
+    fn set_scope(&mut self, scope: ShortcutScope) {
+        self.scope = scope;
+
+        if let Some(ref controller) = self.controller {
+            controller.set_scope(shortcut_scope(scope));
+        }
+    }
+
+    fn set_shortcuts(&mut self, shortcuts: &[Shortcut], on_shortcut: impl Fn(usize) + 'static) {
+        if let Some(controller) = self.controller.take() {
+            self.widget.remove_controller(&controller);
+        }
+
+        let on_shortcut = Rc::new(on_shortcut);
+
+        let controller = gtk4::ShortcutController::new();
+        controller.set_scope(shortcut_scope(self.scope));
+        controller.set_propagation_phase(propagation_phase(self.disabled));
+
+        for (index, shortcut) in shortcuts.iter().enumerate() {
+            let Some(keyval) = keyval(&shortcut.key) else {
+                continue;
+            };
+
+            let trigger = gtk4::KeyvalTrigger::new(
+                keyval,
+                modifier_type(shortcut.modifiers),
+            );
+            let action = gtk4::CallbackAction::new({
+                let on_shortcut = on_shortcut.clone();
+
+                move |_, _| {
+                    on_shortcut(index);
+                    glib::Propagation::Stop
+                }
+            });
+
+            controller.add_shortcut(gtk4::Shortcut::new(
+                Some(trigger),
+                Some(action),
+            ));
+        }
+
+        self.controller = Some(controller.clone());
+        self.widget.add_controller(controller);
+    }
+}
+
+// disabled wrappers turn their controllers off, rather than making their contents insensitive
+pub(crate) fn propagation_phase(disabled: bool) -> gtk4::PropagationPhase {
+    match disabled {
+        true => gtk4::PropagationPhase::None,
+        false => gtk4::PropagationPhase::Bubble,
+    }
+}
+
+fn shortcut_scope(scope: ShortcutScope) -> gtk4::ShortcutScope {
+    match scope {
+        ShortcutScope::Subtree => gtk4::ShortcutScope::Local,
+        ShortcutScope::Window => gtk4::ShortcutScope::Global,
+    }
+}
+
+// identifies the event across every controller it propagates through
+fn serial(controller: &gtk4::EventControllerKey, keycode: u32, phase: KeyPhase) -> u64 {
+    let time = controller.current_event_time() as u64;
+    let released = matches!(phase, KeyPhase::Released) as u64;
+
+    (time << 32) | ((keycode as u64) << 1) | released
+}
+
+pub(crate) fn key(keyval: gdk4::Key) -> Key {
+    match keyval {
+        gdk4::Key::Return | gdk4::Key::KP_Enter | gdk4::Key::ISO_Enter => Key::Enter,
+        gdk4::Key::Tab | gdk4::Key::KP_Tab | gdk4::Key::ISO_Left_Tab => Key::Tab,
+        gdk4::Key::space | gdk4::Key::KP_Space => Key::Space,
+        gdk4::Key::BackSpace => Key::Backspace,
+        gdk4::Key::Delete | gdk4::Key::KP_Delete => Key::Delete,
+        gdk4::Key::Escape => Key::Escape,
+        gdk4::Key::Insert | gdk4::Key::KP_Insert => Key::Insert,
+        gdk4::Key::Home | gdk4::Key::KP_Home => Key::Home,
+        gdk4::Key::End | gdk4::Key::KP_End => Key::End,
+        gdk4::Key::Page_Up | gdk4::Key::KP_Page_Up => Key::PageUp,
+        gdk4::Key::Page_Down | gdk4::Key::KP_Page_Down => Key::PageDown,
+        gdk4::Key::Up | gdk4::Key::KP_Up => Key::ArrowUp,
+        gdk4::Key::Down | gdk4::Key::KP_Down => Key::ArrowDown,
+        gdk4::Key::Left | gdk4::Key::KP_Left => Key::ArrowLeft,
+        gdk4::Key::Right | gdk4::Key::KP_Right => Key::ArrowRight,
+        gdk4::Key::Shift_L | gdk4::Key::Shift_R => Key::Shift,
+        gdk4::Key::Control_L | gdk4::Key::Control_R => Key::Control,
+        gdk4::Key::Alt_L | gdk4::Key::Alt_R | gdk4::Key::ISO_Level3_Shift => Key::Alt,
+        gdk4::Key::Super_L | gdk4::Key::Super_R | gdk4::Key::Meta_L | gdk4::Key::Meta_R => {
+            Key::Meta
+        }
+        gdk4::Key::Caps_Lock => Key::CapsLock,
+
+        keyval => {
+            if let Some(n) = (keyval.name()).and_then(|name| name.strip_prefix('F')?.parse().ok()) {
+                return Key::F(n);
+            }
+
+            match keyval.to_unicode() {
+                Some(c) if !c.is_control() => Key::Character(c.to_string()),
+                _ => Key::Unidentified(keyval.into_glib()),
+            }
+        }
+    }
+}
+
+fn keyval(key: &Key) -> Option<gdk4::Key> {
+    let keyval = match key {
+        Key::Character(text) => {
+            let mut chars = text.chars();
+
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => gdk4::Key::from_unicode(c),
+                _ => return None,
+            }
+        }
+
+        Key::Enter => gdk4::Key::Return,
+        Key::Tab => gdk4::Key::Tab,
+        Key::Space => gdk4::Key::space,
+        Key::Backspace => gdk4::Key::BackSpace,
+        Key::Delete => gdk4::Key::Delete,
+        Key::Escape => gdk4::Key::Escape,
+        Key::Insert => gdk4::Key::Insert,
+        Key::Home => gdk4::Key::Home,
+        Key::End => gdk4::Key::End,
+        Key::PageUp => gdk4::Key::Page_Up,
+        Key::PageDown => gdk4::Key::Page_Down,
+        Key::ArrowUp => gdk4::Key::Up,
+        Key::ArrowDown => gdk4::Key::Down,
+        Key::ArrowLeft => gdk4::Key::Left,
+        Key::ArrowRight => gdk4::Key::Right,
+        Key::F(n) => gdk4::Key::from_name(format!("F{n}"))?,
+        Key::Shift => gdk4::Key::Shift_L,
+        Key::Control => gdk4::Key::Control_L,
+        Key::Alt => gdk4::Key::Alt_L,
+        Key::Meta => gdk4::Key::Super_L,
+        Key::CapsLock => gdk4::Key::Caps_Lock,
+        Key::Unidentified(_) => return None,
+    };
+
+    Some(keyval)
+}
+
+fn modifier_type(modifiers: Modifiers) -> gdk4::ModifierType {
+    let mut state = gdk4::ModifierType::empty();
+
+    if modifiers.shift {
+        state |= gdk4::ModifierType::SHIFT_MASK;
+    }
+
+    if modifiers.ctrl {
+        state |= gdk4::ModifierType::CONTROL_MASK;
+    }
+
+    if modifiers.alt {
+        state |= gdk4::ModifierType::ALT_MASK;
+    }
+
+    if modifiers.meta {
+        state |= gdk4::ModifierType::SUPER_MASK;
+    }
+
+    state
+}
@@ -0,0 +1,193 @@
+use std::rc::Rc;
+
+use gio::prelude::{ActionExt, ActionMapExt, MenuModelExt};
+use glib::{object::Cast, variant::ToVariant};
+use gtk4::prelude::{EventControllerExt, GestureSingleExt, PopoverExt, WidgetExt};
+use ori_native_core::{
+    NativeWidget,
+    native::{HasContextMenu, MenuEntry, NativeContextMenu},
+};
+
+use crate::{
+    Platform,
+    widgets::{group::GroupWidget, key::propagation_phase},
+};
+
+impl HasContextMenu for Platform {
+    type ContextMenu = ContextMenu;
+}
+
+pub struct ContextMenu {
+    widget: GroupWidget,
+    menu:   gtk4::PopoverMenu,
+    click:  gtk4::GestureClick,
+    keys:   gtk4::EventControllerKey,
+}
+
+impl NativeWidget<Platform> for ContextMenu {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeContextMenu<Platform> for ContextMenu {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        let menu = gtk4::PopoverMenu::from_model(None::<&gio::MenuModel>);
+        menu.set_has_arrow(false);
+        menu.set_halign(gtk4::Align::Start);
+        widget.add_popover(menu.upcast_ref());
+
+        let click = gtk4::GestureClick::new();
+        click.set_button(gdk4::BUTTON_SECONDARY);
+        click.connect_pressed({
+            let menu = menu.downgrade();
+
+            move |gesture, _, x, y| {
+                if let Some(menu) = menu.upgrade() {
+                    gesture.set_state(gtk4::EventSequenceState::Claimed);
+                    popup(&menu, x, y);
+                }
+            }
+        });
+
+        // the menu key and shift+f10 open the menu from the keyboard
+        let keys = gtk4::EventControllerKey::new();
+        keys.connect_key_pressed({
+            let menu = menu.downgrade();
+            let widget = widget.downgrade();
+
+            move |_, key, _, state| {
+                let shift = state.contains(gdk4::ModifierType::SHIFT_MASK);
+
+                if key != gdk4::Key::Menu && !(key == gdk4::Key::F10 && shift) {
+                    return glib::Propagation::Proceed;
+                }
+
+                if let (Some(menu), Some(widget)) = (menu.upgrade(), widget.upgrade()) {
+                    let x = widget.width() as f64 / 2.0;
+                    let y = widget.height() as f64 / 2.0;
+                    popup(&menu, x, y);
+                }
+
+                glib::Propagation::Stop
+            }
+        });
+
+        widget.add_controller(click.clone());
+        widget.add_controller(keys.clone());
+
+        Self {
+            widget,
+            menu,
+            click,
+            keys,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {
+        self.widget.remove_popover(self.menu.upcast_ref());
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width as i32, height as i32);
+        (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.click
+            .set_propagation_phase(propagation_phase(disabled));
+        self.keys.set_propagation_phase(propagation_phase(disabled));
+    }
+
+    fn set_menu(&mut self, entries: &[MenuEntry], on_select: impl Fn(usize) + 'static) {
+        let on_select: Rc<dyn Fn(usize)> = Rc::new(on_select);
+
+        let actions = gio::SimpleActionGroup::new();
+        let model = menu_model(entries, &actions, &on_select, &mut 0);
+
+        self.widget.insert_action_group("menu", Some(&actions));
+        self.menu.set_menu_model(Some(&model));
+    }
+}
+
+fn popup(menu: &gtk4::PopoverMenu, x: f64, y: f64) {
+    let rect = gdk4::Rectangle::new(x as i32, y as i32, 1, 1);
+    menu.set_pointing_to(Some(&rect));
+    menu.popup();
+}
+
+// separators split the menu into sections, which is how gio menus draw them
+fn menu_model(
+    entries: &[MenuEntry],
+    actions: &gio::SimpleActionGroup,
+    on_select: &Rc<dyn Fn(usize)>,
+    index: &mut usize,
+) -> gio::Menu {
+    let menu = gio::Menu::new();
+    let mut section = gio::Menu::new();
+
+    for entry in entries {
+        match entry {
+            MenuEntry::Item {
+                label,
+                checked,
+                enabled,
+            } => {
+                let name = format!("item{index}");
+
+                // a boolean state makes the item checkable
+                let action = match checked {
+                    Some(checked) => {
+                        gio::SimpleAction::new_stateful(&name, None, &checked.to_variant())
+                    }
+                    None => gio::SimpleAction::new(&name, None),
+                };
+
+                action.set_enabled(*enabled);
+                action.connect_activate({
+                    let on_select = on_select.clone();
+                    let index = *index;
+
+                    move |action, _| {
+                        // stateful actions don't toggle by themselves when activated
+                        if let Some(checked) = action.state().and_then(|state| state.get::<bool>())
+                        {
+                            action.set_state(&(!checked).to_variant());
+                        }
+
+                        on_select(index)
+                    }
+                });
+
+                actions.add_action(&action);
+                section.append(
+                    Some(label),
+                    Some(&format!("menu.{name}")),
+                );
+
+                *index += 1;
+            }
+
+            MenuEntry::Separator => {
+                if section.n_items() > 0 {
+                    menu.append_section(None, &section);
+                    section = gio::Menu::new();
+                }
+            }
+
+            MenuEntry::Submenu { label, entries } => {
+                let submenu = menu_model(entries, actions, on_select, index);
+                section.append_submenu(Some(label), &submenu);
+            }
+        }
+    }
+
+    if section.n_items() > 0 {
+        menu.append_section(None, &section);
+    }
+
+    menu
+}
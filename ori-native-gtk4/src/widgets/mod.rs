@@ -1,9 +1,21 @@
+mod canvas;
+mod dialog;
+mod dragdrop;
+mod draggable;
+mod file_dialog;
+mod focus;
+mod gesture;
 mod group;
 mod image;
+mod key;
+mod menu;
+mod pointer;
+mod popover;
 mod pressable;
 mod scroll;
 mod text;
 mod textinput;
+mod tooltip;
 mod window;
 
 pub use window::Window;
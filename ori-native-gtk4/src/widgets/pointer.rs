@@ -0,0 +1,122 @@
+use std::rc::Rc;
+
+use gtk4::prelude::{EventControllerExt, WidgetExt};
+use ori_native_core::{
+    CursorIcon, NativeWidget, Point,
+    native::{HasPointer, NativePointer, PointerEvent},
+};
+
+use crate::{
+    Platform,
+    widgets::{group::GroupWidget, key::propagation_phase},
+};
+
+impl HasPointer for Platform {
+    type Pointer = Pointer;
+}
+
+pub struct Pointer {
+    widget:   GroupWidget,
+    disabled: bool,
+    motion:   Option<gtk4::EventControllerMotion>,
+}
+
+impl NativeWidget<Platform> for Pointer {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativePointer<Platform> for Pointer {
+    fn build(_platform: &mut Platform, contents: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+
+        Self {
+            widget,
+            disabled: false,
+            motion: None,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {}
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width as i32, height as i32);
+        (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+
+        if let Some(ref motion) = self.motion {
+            motion.set_propagation_phase(propagation_phase(disabled));
+        }
+    }
+
+    fn set_cursor(&mut self, cursor: Option<CursorIcon>) {
+        let cursor = cursor.map(|cursor| gdk4::Cursor::from_name(cursor_name(cursor), None));
+        self.widget.set_cursor(cursor.flatten().as_ref());
+    }
+
+    fn set_on_pointer(&mut self, on_pointer: impl Fn(PointerEvent) + 'static) {
+        if let Some(motion) = self.motion.take() {
+            self.widget.remove_controller(&motion);
+        }
+
+        let on_pointer = Rc::new(on_pointer);
+
+        let motion = gtk4::EventControllerMotion::new();
+        motion.set_propagation_phase(propagation_phase(self.disabled));
+
+        motion.connect_enter({
+            let on_pointer = on_pointer.clone();
+            move |_, x, y| on_pointer(PointerEvent::Entered(point(x, y)))
+        });
+
+        motion.connect_motion({
+            let on_pointer = on_pointer.clone();
+            move |_, x, y| on_pointer(PointerEvent::Moved(point(x, y)))
+        });
+
+        motion.connect_leave({
+            let on_pointer = on_pointer.clone();
+            move |_| on_pointer(PointerEvent::Left)
+        });
+
+        self.motion = Some(motion.clone());
+        self.widget.add_controller(motion);
+    }
+}
+
+fn cursor_name(cursor: CursorIcon) -> &'static str {
+    match cursor {
+        CursorIcon::Default => "default",
+        CursorIcon::Hidden => "none",
+        CursorIcon::Pointer => "pointer",
+        CursorIcon::Text => "text",
+        CursorIcon::Crosshair => "crosshair",
+        CursorIcon::Move => "move",
+        CursorIcon::Grab => "grab",
+        CursorIcon::Grabbing => "grabbing",
+        CursorIcon::NotAllowed => "not-allowed",
+        CursorIcon::Wait => "wait",
+        CursorIcon::Progress => "progress",
+        CursorIcon::Help => "help",
+        CursorIcon::ResizeHorizontal => "ew-resize",
+        CursorIcon::ResizeVertical => "ns-resize",
+        CursorIcon::ResizeNwse => "nwse-resize",
+        CursorIcon::ResizeNesw => "nesw-resize",
+        CursorIcon::ResizeColumn => "col-resize",
+        CursorIcon::ResizeRow => "row-resize",
+        CursorIcon::ZoomIn => "zoom-in",
+        CursorIcon::ZoomOut => "zoom-out",
+    }
+}
+
+fn point(x: f64, y: f64) -> Point<f32> {
+    Point {
+        x: x as f32,
+        y: y as f32,
+    }
+}
@@ -0,0 +1,82 @@
+use glib::object::ObjectExt;
+use gtk4::prelude::{PopoverExt, WidgetExt};
+use ori_native_core::{
+    NativeWidget,
+    native::{HasPopover, NativePopover, PopoverPosition},
+};
+
+use crate::{Platform, widgets::group::GroupWidget};
+
+impl HasPopover for Platform {
+    type Popover = Popover;
+}
+
+pub struct Popover {
+    widget:   GroupWidget,
+    popover:  gtk4::Popover,
+    content:  gtk4::Widget,
+    on_close: Option<glib::SignalHandlerId>,
+}
+
+impl NativeWidget<Platform> for Popover {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativePopover<Platform> for Popover {
+    fn build(_platform: &mut Platform, anchor: &gtk4::Widget, content: &gtk4::Widget) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, anchor);
+
+        let popover = gtk4::Popover::new();
+        popover.set_child(Some(content));
+        widget.add_popover(&popover);
+
+        Self {
+            widget,
+            popover,
+            content: content.clone(),
+            on_close: None,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {
+        self.popover.set_child(None::<&gtk4::Widget>);
+        self.widget.remove_popover(&self.popover);
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width as i32, height as i32);
+        (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
+    }
+
+    fn set_content_size(&mut self, width: f32, height: f32) {
+        self.content.set_size_request(width as i32, height as i32);
+    }
+
+    fn set_position(&mut self, position: PopoverPosition) {
+        self.popover.set_position(match position {
+            PopoverPosition::Top => gtk4::PositionType::Top,
+            PopoverPosition::Bottom => gtk4::PositionType::Bottom,
+            PopoverPosition::Left => gtk4::PositionType::Left,
+            PopoverPosition::Right => gtk4::PositionType::Right,
+        });
+    }
+
+    fn set_open(&mut self, open: bool) {
+        match open {
+            true => self.popover.popup(),
+            false => self.popover.popdown(),
+        }
+    }
+
+    fn set_on_close(&mut self, on_close: impl Fn() + 'static) {
+        if let Some(handler) = self.on_close.take() {
+            self.popover.disconnect(handler);
+        }
+
+        let handler = self.popover.connect_closed(move |_| on_close());
+        self.on_close = Some(handler);
+    }
+}
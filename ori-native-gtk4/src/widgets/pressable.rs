@@ -1,9 +1,10 @@
-use std::sync::Arc;
+use std::{cell::Cell, rc::Rc, sync::Arc};
 
-use gtk4::prelude::{AccessibleExt, WidgetExt};
+use glib::object::{Cast, ObjectExt};
+use gtk4::prelude::{AccessibleExt, EventControllerExt, GestureSingleExt, WidgetExt};
 use ori_native_core::{
-    NativeWidget,
-    native::{HasPressable, NativePressable, Press},
+    Modifiers, NativeWidget, Point, PointerButton,
+    native::{HasPressable, NativePressable, Press, PressEvent},
 };
 
 use crate::{Platform, widgets::group::GroupWidget};
@@ -13,10 +14,12 @@ impl HasPressable for Platform {
 }
 
 pub struct Pressable {
-    widget: GroupWidget,
-    press:  Option<gtk4::GestureClick>,
-    hover:  Option<gtk4::EventControllerMotion>,
-    focus:  Option<gtk4::EventControllerFocus>,
+    widget:     GroupWidget,
+    press:      Option<gtk4::GestureClick>,
+    long_press: Option<gtk4::GestureLongPress>,
+    keys:       Option<gtk4::EventControllerKey>,
+    hover:      Option<gtk4::EventControllerMotion>,
+    focus:      Option<gtk4::EventControllerFocus>,
 }
 
 impl NativeWidget<Platform> for Pressable {
@@ -35,6 +38,8 @@ impl NativePressable<Platform> for Pressable {
         Self {
             widget,
             press: None,
+            long_press: None,
+            keys: None,
             hover: None,
             focus: None,
         }
@@ -47,31 +52,151 @@ impl NativePressable<Platform> for Pressable {
         (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
     }
 
-    fn set_on_press(&mut self, on_press: impl Fn(Press) + 'static) {
+    fn set_on_press(&mut self, on_press: impl Fn(PressEvent) + 'static) {
         if let Some(press) = self.press.take() {
             self.widget.remove_controller(&press);
         }
 
+        if let Some(long_press) = self.long_press.take() {
+            self.widget.remove_controller(&long_press);
+        }
+
+        if let Some(keys) = self.keys.take() {
+            self.widget.remove_controller(&keys);
+        }
+
         let on_press = Arc::new(on_press);
 
-        let controller = gtk4::GestureClick::new();
-        controller.connect_pressed({
+        // listen to every button, the view decides what each of them means
+        let click = gtk4::GestureClick::new();
+        click.set_button(0);
+
+        click.connect_pressed({
             let on_press = on_press.clone();
-            move |_, _, _, _| on_press(Press::Pressed)
+            move |gesture, clicks, x, y| {
+                on_press(press_event(
+                    gesture.upcast_ref(),
+                    Press::Pressed,
+                    clicks,
+                    x,
+                    y,
+                ))
+            }
         });
 
-        controller.connect_released({
+        click.connect_released({
             let on_press = on_press.clone();
-            move |_, _, _, _| on_press(Press::Released)
+            move |gesture, clicks, x, y| {
+                on_press(press_event(
+                    gesture.upcast_ref(),
+                    Press::Released,
+                    clicks,
+                    x,
+                    y,
+                ))
+            }
         });
 
-        controller.connect_unpaired_release({
+        click.connect_unpaired_release({
             let on_press = on_press.clone();
-            move |_, _, _, _, _| on_press(Press::Cancelled)
+            move |gesture, x, y, _, _| {
+                on_press(press_event(
+                    gesture.upcast_ref(),
+                    Press::Cancelled,
+                    1,
+                    x,
+                    y,
+                ))
+            }
         });
 
-        self.press = Some(controller.clone());
-        self.widget.add_controller(controller);
+        let long_press = gtk4::GestureLongPress::new();
+        long_press.connect_pressed({
+            let on_press = on_press.clone();
+            move |gesture, x, y| {
+                on_press(press_event(
+                    gesture.upcast_ref(),
+                    Press::LongPressed,
+                    1,
+                    x,
+                    y,
+                ))
+            }
+        });
+
+        // space and enter activate the focused pressable like a click with the primary button
+        let keys = gtk4::EventControllerKey::new();
+        let activating = Rc::new(Cell::new(None));
+
+        keys.connect_key_pressed({
+            let on_press = on_press.clone();
+            let activating = activating.clone();
+            let widget = self.widget.downgrade();
+
+            move |_, key, _, state| {
+                if !is_activation_key(key) {
+                    return glib::Propagation::Proceed;
+                }
+
+                if activating.get().is_none()
+                    && let Some(widget) = widget.upgrade()
+                {
+                    activating.set(Some(key));
+                    on_press(key_event(
+                        &widget,
+                        Press::Pressed,
+                        state,
+                    ));
+                }
+
+                glib::Propagation::Stop
+            }
+        });
+
+        keys.connect_key_released({
+            let on_press = on_press.clone();
+            let widget = self.widget.downgrade();
+
+            move |_, key, _, state| {
+                if activating.get() == Some(key)
+                    && let Some(widget) = widget.upgrade()
+                {
+                    activating.set(None);
+                    on_press(key_event(
+                        &widget,
+                        Press::Released,
+                        state,
+                    ));
+                }
+            }
+        });
+
+        self.press = Some(click.clone());
+        self.long_press = Some(long_press.clone());
+        self.keys = Some(keys.clone());
+        self.widget.add_controller(click);
+        self.widget.add_controller(long_press);
+        self.widget.add_controller(keys);
+    }
+
+    fn set_focusable(&mut self, focusable: bool) {
+        self.widget.set_focusable(focusable);
+        self.widget.set_focus_on_click(focusable);
+    }
+
+    fn set_tab_index(&mut self, tab_index: i32) {
+        self.widget.set_tab_index(tab_index);
+    }
+
+    fn set_focus_ring(&mut self, focus_ring: bool) {
+        match focus_ring {
+            true => self.widget.add_css_class("focus-ring"),
+            false => self.widget.remove_css_class("focus-ring"),
+        }
+    }
+
+    fn set_disabled(&mut self, disabled: bool) {
+        self.widget.set_sensitive(!disabled);
     }
 
     fn set_on_hover(&mut self, on_hover: impl Fn(bool) + 'static) {
@@ -118,3 +243,60 @@ impl NativePressable<Platform> for Pressable {
         self.widget.add_controller(controller);
     }
 }
+
+fn press_event(
+    gesture: &gtk4::GestureSingle,
+    press: Press,
+    clicks: i32,
+    x: f64,
+    y: f64,
+) -> PressEvent {
+    let button = match gesture.current_button() {
+        // touch events don't have a button, they behave like the primary one
+        0 | gdk4::BUTTON_PRIMARY => PointerButton::Primary,
+        gdk4::BUTTON_SECONDARY => PointerButton::Secondary,
+        gdk4::BUTTON_MIDDLE => PointerButton::Middle,
+        button => PointerButton::Other(button),
+    };
+
+    PressEvent {
+        press,
+        button,
+        clicks: clicks.max(1) as u32,
+        position: Point {
+            x: x as f32,
+            y: y as f32,
+        },
+        modifiers: modifiers(gesture.current_event_state()),
+    }
+}
+
+fn is_activation_key(key: gdk4::Key) -> bool {
+    matches!(
+        key,
+        gdk4::Key::space | gdk4::Key::KP_Space | gdk4::Key::Return | gdk4::Key::KP_Enter
+    )
+}
+
+fn key_event(widget: &GroupWidget, press: Press, state: gdk4::ModifierType) -> PressEvent {
+    // keyboard activation has no pointer position, the center of the widget stands in for it
+    PressEvent {
+        press,
+        button: PointerButton::Primary,
+        clicks: 1,
+        position: Point {
+            x: widget.width() as f32 / 2.0,
+            y: widget.height() as f32 / 2.0,
+        },
+        modifiers: modifiers(state),
+    }
+}
+
+pub(crate) fn modifiers(state: gdk4::ModifierType) -> Modifiers {
+    Modifiers {
+        shift: state.contains(gdk4::ModifierType::SHIFT_MASK),
+        ctrl:  state.contains(gdk4::ModifierType::CONTROL_MASK),
+        alt:   state.contains(gdk4::ModifierType::ALT_MASK),
+        meta:  state.intersects(gdk4::ModifierType::SUPER_MASK | gdk4::ModifierType::META_MASK),
+    }
+}
@@ -1,7 +1,15 @@
-use gtk4::prelude::WidgetExt;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use glib::object::{Cast, ObjectExt};
+use gtk4::prelude::{
+    AdjustmentExt, EventControllerExt, GestureDragExt, GestureSingleExt, WidgetExt,
+};
 use ori_native_core::{
-    Direction, NativeWidget,
-    native::{HasScroll, NativeScroll},
+    NativeWidget, Size,
+    native::{HasScroll, NativeScroll, ScrollOffset, ScrollbarPolicy},
 };
 
 use crate::Platform;
@@ -11,12 +19,38 @@ impl HasScroll for Platform {
 }
 
 pub struct Scroll {
-    scroll: gtk4::ScrolledWindow,
+    overlay:       gtk4::Overlay,
+    scroll:        gtk4::ScrolledWindow,
+    indicator:     Option<gtk4::Widget>,
+    on_overscroll: Option<(
+        gtk4::EventControllerScroll,
+        gtk4::GestureDrag,
+    )>,
+    stick_to_end:  Rc<Cell<bool>>,
+    hscroll:       Rc<AxisState>,
+    vscroll:       Rc<AxisState>,
+    on_scroll:     Vec<(gtk4::Adjustment, glib::SignalHandlerId)>,
+}
+
+#[derive(Default)]
+struct AxisState {
+    at_end:    Cell<bool>,
+    pending:   Cell<Option<f64>>,
+    applying:  Cell<bool>,
+    animation: RefCell<Option<gtk4::TickCallbackId>>,
+}
+
+impl AxisState {
+    fn set_value(&self, adjustment: &gtk4::Adjustment, value: f64) {
+        self.applying.set(true);
+        adjustment.set_value(value);
+        self.applying.set(false);
+    }
 }
 
 impl NativeWidget<Platform> for Scroll {
     fn widget(&self) -> &gtk4::Widget {
-        self.scroll.as_ref()
+        self.overlay.as_ref()
     }
 }
 
@@ -25,10 +59,47 @@ impl NativeScroll<Platform> for Scroll {
         let scroll = gtk4::ScrolledWindow::new();
         scroll.set_child(Some(contents));
 
-        Self { scroll }
+        // the refresh indicator is drawn on top of the scrolled contents
+        let overlay = gtk4::Overlay::new();
+        overlay.set_child(Some(&scroll));
+
+        let stick_to_end = Rc::new(Cell::new(false));
+        let hscroll = Rc::new(AxisState::default());
+        let vscroll = Rc::new(AxisState::default());
+
+        hscroll.at_end.set(true);
+        vscroll.at_end.set(true);
+
+        track_axis(
+            &scroll.hadjustment(),
+            &hscroll,
+            &stick_to_end,
+        );
+        track_axis(
+            &scroll.vadjustment(),
+            &vscroll,
+            &stick_to_end,
+        );
+
+        Self {
+            overlay,
+            scroll,
+            indicator: None,
+            on_overscroll: None,
+            stick_to_end,
+            hscroll,
+            vscroll,
+            on_scroll: Vec::new(),
+        }
     }
 
-    fn teardown(self, _platform: &mut Platform) {}
+    fn teardown(self, _platform: &mut Platform) {
+        for axis in [&self.hscroll, &self.vscroll] {
+            if let Some(animation) = axis.animation.take() {
+                animation.remove();
+            }
+        }
+    }
 
     fn set_size(&mut self, width: f32, height: f32) {
         self.scroll.set_size_request(
@@ -37,15 +108,317 @@ impl NativeScroll<Platform> for Scroll {
         );
     }
 
-    fn set_direction(&mut self, direction: Direction) {
-        self.scroll.set_hscrollbar_policy(match direction {
-            Direction::Horizontal => gtk4::PolicyType::Automatic,
-            Direction::Vertical => gtk4::PolicyType::Never,
+    fn set_scrollbars(
+        &mut self,
+        horizontal: Option<ScrollbarPolicy>,
+        vertical: Option<ScrollbarPolicy>,
+    ) {
+        self.scroll.set_hscrollbar_policy(policy_type(horizontal));
+        self.scroll.set_vscrollbar_policy(policy_type(vertical));
+
+        // overlay scrolling is a property of the whole window, not of the individual scrollbars
+        let overlay = [horizontal, vertical].contains(&Some(ScrollbarPolicy::Overlay));
+        self.scroll.set_overlay_scrolling(overlay);
+    }
+
+    fn set_kinetic(&mut self, kinetic: bool) {
+        self.scroll.set_kinetic_scrolling(kinetic);
+    }
+
+    fn set_stick_to_end(&mut self, stick_to_end: bool) {
+        self.stick_to_end.set(stick_to_end);
+    }
+
+    fn set_on_scroll(&mut self, on_scroll: impl Fn(ScrollOffset) + 'static) {
+        for (adjustment, handler) in self.on_scroll.drain(..) {
+            adjustment.disconnect(handler);
+        }
+
+        let hadjustment = self.scroll.hadjustment();
+        let vadjustment = self.scroll.vadjustment();
+
+        let emit = Rc::new({
+            let hadjustment = hadjustment.downgrade();
+            let vadjustment = vadjustment.downgrade();
+
+            move || {
+                if let Some(hadjustment) = hadjustment.upgrade()
+                    && let Some(vadjustment) = vadjustment.upgrade()
+                {
+                    on_scroll(ScrollOffset {
+                        x:        hadjustment.value() as f32,
+                        y:        vadjustment.value() as f32,
+                        content:  Size {
+                            width:  hadjustment.upper() as f32,
+                            height: vadjustment.upper() as f32,
+                        },
+                        viewport: Size {
+                            width:  hadjustment.page_size() as f32,
+                            height: vadjustment.page_size() as f32,
+                        },
+                    });
+                }
+            }
+        });
+
+        // changes to the extent of the contents or the viewport are reported too, as they can
+        // bring an edge into reach without the offset changing
+        for adjustment in [&hadjustment, &vadjustment] {
+            let value_changed = adjustment.connect_value_changed({
+                let emit = emit.clone();
+                move |_| emit()
+            });
+
+            let changed = adjustment.connect_changed({
+                let emit = emit.clone();
+                move |_| emit()
+            });
+
+            self.on_scroll.push((adjustment.clone(), value_changed));
+            self.on_scroll.push((adjustment.clone(), changed));
+        }
+    }
+
+    fn set_on_overscroll(&mut self, on_overscroll: impl Fn() + 'static) {
+        if let Some((wheel, drag)) = self.on_overscroll.take() {
+            self.scroll.remove_controller(&wheel);
+            self.scroll.remove_controller(&drag);
+        }
+
+        let on_overscroll = Rc::new(on_overscroll);
+        let adjustment = self.scroll.vadjustment();
+
+        // the distance pulled past the top has to exceed a threshold, so that flicking the wheel
+        // against the top doesn't refresh
+        let pulled = Rc::new(Cell::new(0.0));
+
+        let wheel = gtk4::EventControllerScroll::new(gtk4::EventControllerScrollFlags::VERTICAL);
+        wheel.set_propagation_phase(gtk4::PropagationPhase::Capture);
+
+        wheel.connect_scroll({
+            let on_overscroll = on_overscroll.clone();
+            let adjustment = adjustment.clone();
+            let pulled = pulled.clone();
+
+            move |wheel, _, dy| {
+                let delta = match wheel.unit() {
+                    gdk4::ScrollUnit::Wheel => dy * WHEEL_STEP,
+                    _ => dy,
+                };
+
+                if delta < 0.0 && adjustment.value() <= adjustment.lower() {
+                    pulled.set(pulled.get() - delta);
+                } else {
+                    pulled.set(0.0);
+                }
+
+                if pulled.get() >= REFRESH_DISTANCE {
+                    pulled.set(0.0);
+                    on_overscroll();
+                }
+
+                glib::Propagation::Proceed
+            }
         });
 
-        self.scroll.set_vscrollbar_policy(match direction {
-            Direction::Horizontal => gtk4::PolicyType::Never,
-            Direction::Vertical => gtk4::PolicyType::Automatic,
+        wheel.connect_scroll_end({
+            let pulled = pulled.clone();
+            move |_| pulled.set(0.0)
+        });
+
+        let drag = gtk4::GestureDrag::new();
+        drag.set_touch_only(true);
+        drag.set_propagation_phase(gtk4::PropagationPhase::Capture);
+
+        // touch drags only count when they start at the top, and refresh at most once
+        let armed = Rc::new(Cell::new(false));
+
+        drag.connect_drag_begin({
+            let adjustment = adjustment.clone();
+            let armed = armed.clone();
+
+            move |_, _, _| {
+                armed.set(adjustment.value() <= adjustment.lower());
+            }
         });
+
+        drag.connect_drag_update(move |_, _, y| {
+            if armed.get() && y >= REFRESH_DISTANCE {
+                armed.set(false);
+                on_overscroll();
+            }
+        });
+
+        self.scroll.add_controller(wheel.clone());
+        self.scroll.add_controller(drag.clone());
+        self.on_overscroll = Some((wheel, drag));
+    }
+
+    fn set_refreshing(&mut self, refreshing: bool, indicator: Option<&gtk4::Widget>) {
+        if let Some(current) = self.indicator.take() {
+            self.overlay.remove_overlay(&current);
+        }
+
+        if !refreshing {
+            return;
+        }
+
+        // without a custom indicator the platform spinner is shown
+        let indicator = match indicator {
+            Some(indicator) => indicator.clone(),
+            None => {
+                let spinner = gtk4::Spinner::new();
+                spinner.start();
+                spinner.upcast()
+            }
+        };
+
+        indicator.set_halign(gtk4::Align::Center);
+        indicator.set_valign(gtk4::Align::Start);
+        indicator.set_margin_top(8);
+
+        self.overlay.add_overlay(&indicator);
+        self.indicator = Some(indicator);
+    }
+
+    fn scroll_to(&mut self, x: Option<f32>, y: Option<f32>, animated: bool) {
+        if let Some(x) = x {
+            scroll_axis(
+                &self.scroll,
+                &self.scroll.hadjustment(),
+                &self.hscroll,
+                x as f64,
+                animated,
+            );
+        }
+
+        if let Some(y) = y {
+            scroll_axis(
+                &self.scroll,
+                &self.scroll.vadjustment(),
+                &self.vscroll,
+                y as f64,
+                animated,
+            );
+        }
+    }
+}
+
+const SCROLL_DURATION: f64 = 0.2;
+const REFRESH_DISTANCE: f64 = 80.0;
+const WHEEL_STEP: f64 = 20.0;
+
+fn policy_type(policy: Option<ScrollbarPolicy>) -> gtk4::PolicyType {
+    match policy {
+        None => gtk4::PolicyType::Never,
+
+        // `Never` in gtk also disables scrolling, `External` hides the scrollbar but keeps the
+        // axis scrollable
+        Some(ScrollbarPolicy::Never) => gtk4::PolicyType::External,
+        Some(ScrollbarPolicy::Always) => gtk4::PolicyType::Always,
+        Some(ScrollbarPolicy::Automatic) => gtk4::PolicyType::Automatic,
+        Some(ScrollbarPolicy::Overlay) => gtk4::PolicyType::Automatic,
     }
 }
+
+fn track_axis(adjustment: &gtk4::Adjustment, axis: &Rc<AxisState>, stick_to_end: &Rc<Cell<bool>>) {
+    adjustment.connect_value_changed({
+        let axis = axis.clone();
+
+        move |adjustment| {
+            axis.at_end.set(is_at_end(adjustment));
+
+            // once the user scrolls, a target that couldn't be reached yet is abandoned
+            if !axis.applying.get() {
+                axis.pending.set(None);
+            }
+        }
+    });
+
+    // when the extent of the contents changes, either apply the offset that couldn't be reached
+    // before, or keep the end in view if that is where the user was
+    adjustment.connect_changed({
+        let axis = axis.clone();
+        let stick_to_end = stick_to_end.clone();
+
+        move |adjustment| {
+            if let Some(target) = axis.pending.take() {
+                axis.set_value(adjustment, target);
+
+                if adjustment.value() < target {
+                    axis.pending.set(Some(target));
+                }
+            } else if stick_to_end.get() && axis.at_end.get() && !is_at_end(adjustment) {
+                adjustment.set_value(adjustment.upper() - adjustment.page_size());
+            }
+        }
+    });
+}
+
+fn is_at_end(adjustment: &gtk4::Adjustment) -> bool {
+    adjustment.value() + adjustment.page_size() >= adjustment.upper() - 1.0
+}
+
+fn scroll_axis(
+    scroll: &gtk4::ScrolledWindow,
+    adjustment: &gtk4::Adjustment,
+    axis: &Rc<AxisState>,
+    target: f64,
+    animated: bool,
+) {
+    if let Some(animation) = axis.animation.take() {
+        animation.remove();
+    }
+
+    // the contents might not have been allocated yet, in which case the target is applied when
+    // the adjustment grows large enough
+    let max = adjustment.upper() - adjustment.page_size();
+    let reachable = target.min(max.max(0.0));
+
+    if reachable < target {
+        axis.pending.set(Some(target));
+    } else {
+        axis.pending.set(None);
+    }
+
+    if !animated || !scroll.is_mapped() {
+        axis.set_value(adjustment, reachable);
+        return;
+    }
+
+    let from = adjustment.value();
+    let start_time = Cell::new(None);
+
+    let animation = scroll.add_tick_callback({
+        let adjustment = adjustment.clone();
+        let axis = Rc::downgrade(axis);
+
+        move |_, clock| {
+            let Some(axis) = axis.upgrade() else {
+                return glib::ControlFlow::Break;
+            };
+
+            let time = clock.frame_time() as f64 / 1_000_000.0;
+            let start = start_time.get().unwrap_or(time);
+            start_time.set(Some(start));
+            let t = ((time - start) / SCROLL_DURATION).min(1.0);
+
+            // ease out cubic
+            let eased = 1.0 - (1.0 - t).powi(3);
+            axis.set_value(
+                &adjustment,
+                from + (reachable - from) * eased,
+            );
+
+            if t < 1.0 {
+                return glib::ControlFlow::Continue;
+            }
+
+            axis.animation.take();
+
+            glib::ControlFlow::Break
+        }
+    });
+
+    axis.animation.replace(Some(animation));
+}
@@ -146,6 +146,10 @@ impl NativeTextInput<Platform> for TextInput {
         self.view.set_accepts_tab(accept_tab);
     }
 
+    fn set_disabled(&mut self, _platform: &mut Platform, disabled: bool) {
+        self.overlay.set_sensitive(!disabled);
+    }
+
     fn set_font(&mut self, platform: &mut Platform, font: Font) {
         platform.set_style(self.view_style, &font_style(&font));
         self.font = font;
@@ -0,0 +1,168 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use glib::object::ObjectExt;
+use gtk4::prelude::WidgetExt;
+use ori_native_core::{
+    NativeWidget,
+    native::{HasTooltip, NativeTooltip},
+};
+
+use crate::{Platform, widgets::group::GroupWidget};
+
+impl HasTooltip for Platform {
+    type Tooltip = Tooltip;
+}
+
+pub struct Tooltip {
+    widget:  GroupWidget,
+    content: Option<gtk4::Widget>,
+    text:    Rc<RefCell<Option<String>>>,
+    delay:   Rc<Cell<Duration>>,
+    pending: Rc<RefCell<Option<glib::SourceId>>>,
+}
+
+impl NativeWidget<Platform> for Tooltip {
+    fn widget(&self) -> &gtk4::Widget {
+        self.widget.as_ref()
+    }
+}
+
+impl NativeTooltip<Platform> for Tooltip {
+    fn build(
+        _platform: &mut Platform,
+        contents: &gtk4::Widget,
+        content: Option<&gtk4::Widget>,
+    ) -> Self {
+        let widget = GroupWidget::new();
+        widget.insert_child(0, contents);
+        widget.set_has_tooltip(true);
+
+        let text = Rc::new(RefCell::new(None::<String>));
+        let delay = Rc::new(Cell::new(Duration::ZERO));
+        let rested = Rc::new(Cell::new(None::<Instant>));
+        let pending = Rc::new(RefCell::new(None::<glib::SourceId>));
+
+        // the delay counts from when the pointer last moved, so any motion starts it over
+        let motion = gtk4::EventControllerMotion::new();
+        motion.connect_enter({
+            let rested = rested.clone();
+            let pending = pending.clone();
+
+            move |_, _, _| {
+                rested.set(Some(Instant::now()));
+                cancel(&pending);
+            }
+        });
+
+        motion.connect_motion({
+            let rested = rested.clone();
+            let pending = pending.clone();
+
+            move |_, _, _| {
+                rested.set(Some(Instant::now()));
+                cancel(&pending);
+            }
+        });
+
+        motion.connect_leave({
+            let rested = rested.clone();
+            let pending = pending.clone();
+
+            move |_| {
+                rested.set(None);
+                cancel(&pending);
+            }
+        });
+
+        widget.add_controller(motion);
+
+        // gtk has a fixed delay of its own, longer delays are handled by declining the query
+        // until the pointer has rested long enough, and asking again once it has
+        widget.connect_query_tooltip({
+            let content = content.cloned();
+            let text = text.clone();
+            let delay = delay.clone();
+            let pending = pending.clone();
+
+            move |widget, _, _, keyboard, tooltip| {
+                cancel(&pending);
+
+                let elapsed = rested.get().map_or(Duration::ZERO, |at| at.elapsed());
+
+                if !keyboard && elapsed < delay.get() {
+                    let source = glib::timeout_add_local_once(delay.get() - elapsed, {
+                        let widget = widget.downgrade();
+                        let pending = pending.clone();
+
+                        move || {
+                            // a fired source is already gone, forget it without removing it
+                            pending.take();
+
+                            if let Some(widget) = widget.upgrade() {
+                                widget.trigger_tooltip_query();
+                            }
+                        }
+                    });
+
+                    pending.replace(Some(source));
+
+                    // the default handler would show the tooltip text right away
+                    widget.stop_signal_emission_by_name("query-tooltip");
+                    return false;
+                }
+
+                match content {
+                    Some(ref content) => tooltip.set_custom(Some(content)),
+                    None => tooltip.set_text(text.borrow().as_deref()),
+                }
+
+                true
+            }
+        });
+
+        Self {
+            widget,
+            content: content.cloned(),
+            text,
+            delay,
+            pending,
+        }
+    }
+
+    fn teardown(self, _platform: &mut Platform) {
+        cancel(&self.pending);
+        self.widget.set_has_tooltip(false);
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        self.widget.set_size(width as i32, height as i32);
+        (self.widget).set_child_layout(0, 0, 0, width as i32, height as i32);
+    }
+
+    fn set_content_size(&mut self, width: f32, height: f32) {
+        if let Some(ref content) = self.content {
+            content.set_size_request(width as i32, height as i32);
+        }
+    }
+
+    fn set_text(&mut self, text: Option<String>) {
+        // the text is also what assistive technologies describe the widget with
+        self.widget.set_tooltip_text(text.as_deref());
+        self.widget.set_has_tooltip(true);
+        self.text.replace(text);
+    }
+
+    fn set_delay(&mut self, delay: Duration) {
+        self.delay.set(delay);
+    }
+}
+
+fn cancel(pending: &RefCell<Option<glib::SourceId>>) {
+    if let Some(source) = pending.take() {
+        source.remove();
+    }
+}